@@ -1,27 +1,11 @@
 use leptos::prelude::*;
 use leptos_daisyui_rs::components::*;
 use leptos_icons::Icon;
-use leptos_router::{components::Outlet, hooks::use_location};
-use leptos_use::{BreakpointsTailwind, breakpoints_tailwind, use_breakpoints};
+use leptos_router::components::Outlet;
 
 /// Layout component for the demos
 #[component]
 pub fn Layout() -> impl IntoView {
-    let location = use_location();
-    let breakpoints = use_breakpoints(breakpoints_tailwind());
-
-    let selected = RwSignal::new(None);
-
-    Effect::new(move || {
-        let init_component_name = location
-            .pathname
-            .get_untracked()
-            .strip_prefix("/components/")
-            .map(|v| v.to_string());
-
-        selected.set(init_component_name);
-    });
-
     view! {
         <div class="h-screen w-screen bg-base-100">
             <Navbar class="w-screen bg-base-200 border-b border-base-300">
@@ -45,8 +29,12 @@ pub fn Layout() -> impl IntoView {
                 </NavbarEnd>
             </Navbar>
 
-            <Drawer open=breakpoints.ge(BreakpointsTailwind::Lg)>
-                <DrawerToggle id="drawer-toggle" />
+            <Drawer
+                id="drawer-toggle"
+                open_from=DrawerOpenBreakpoint::Lg
+                close_on_navigate=true
+            >
+                <DrawerToggle />
 
                 <div class="drawer-content">
                     <div class="container mx-auto flex justify-center">
@@ -62,12 +50,11 @@ pub fn Layout() -> impl IntoView {
                 </div>
 
                 <DrawerSide>
-                    <label for="drawer-toggle" class="drawer-overlay"></label>
+                    <DrawerOverlay />
                     <div class="min-h-full w-64 bg-base-200 text-base-content">
                         <div class="p-4">
                             <h2 class="text-lg font-semibold mb-4">"Components"</h2>
                             <Menu
-                                selected=selected
                                 direction=MenuDirection::Vertical
                                 class="w-full"
                             >
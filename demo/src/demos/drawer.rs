@@ -5,7 +5,7 @@ use leptos_icons::Icon;
 
 #[component]
 pub fn DrawerDemo() -> impl IntoView {
-    let (drawer_open, set_drawer_open) = signal(false);
+    let drawer_open = RwSignal::new(false);
 
     view! {
         <ContentLayout
@@ -14,18 +14,15 @@ pub fn DrawerDemo() -> impl IntoView {
         >
             <Section title="Basic Drawer" col=true>
                 <div class="h-96 bg-base-200 rounded-lg overflow-hidden">
-                    <Drawer open=drawer_open>
-                        <DrawerToggle
-                            id="drawer-demo"
-                            on:click=move |_| set_drawer_open.update(|open| *open = !*open)
-                        />
+                    <Drawer open=drawer_open close_on_escape=true close_on_navigate=true>
+                        <DrawerToggle />
 
                         <div class="drawer-content flex flex-col items-center justify-center">
                             <div class="text-center">
                                 <h3 class="text-lg font-semibold mb-4">"Page Content"</h3>
                                 <Button
                                     color=ButtonColor::Primary
-                                    on:click=move |_| set_drawer_open.set(true)
+                                    on:click=move |_| drawer_open.set(true)
                                 >
                                     "Open Drawer"
                                 </Button>
@@ -33,11 +30,7 @@ pub fn DrawerDemo() -> impl IntoView {
                         </div>
 
                         <DrawerSide>
-                            <label
-                                for="drawer-demo"
-                                class="drawer-overlay"
-                                on:click=move |_| set_drawer_open.set(false)
-                            ></label>
+                            <DrawerOverlay />
                             <div class="menu bg-base-200 text-base-content min-h-full w-80 p-4">
                                 <div class="flex justify-between items-center mb-6">
                                     <h3 class="text-lg font-semibold">"Sidebar Menu"</h3>
@@ -45,7 +38,7 @@ pub fn DrawerDemo() -> impl IntoView {
                                         size=ButtonSize::Sm
                                         style=ButtonStyle::Ghost
                                         shape=ButtonShape::Circle
-                                        on:click=move |_| set_drawer_open.set(false)
+                                        on:click=move |_| drawer_open.set(false)
                                     >
                                         <Icon icon=icondata::AiCloseOutlined />
                                     </Button>
@@ -97,7 +90,7 @@ pub fn DrawerDemo() -> impl IntoView {
                         <code>"<Drawer open={{drawer_open}}>"</code>
                     </pre>
                     <pre data-prefix="2">
-                        <code>"  <DrawerToggle id=\"my-drawer\" />"</code>
+                        <code>"  <DrawerToggle />"</code>
                     </pre>
                     <pre data-prefix="3">
                         <code>"  <div class=\"drawer-content\">"</code>
@@ -3,6 +3,9 @@ use leptos_daisyui_rs::components::*;
 
 #[component]
 pub fn FileInputDemo() -> impl IntoView {
+    let selected_files = RwSignal::new_local(Vec::new());
+    let dropped_files = RwSignal::new_local(Vec::new());
+
     view! {
         <div class="space-y-6">
             <h1 class="text-3xl font-bold">"File Input"</h1>
@@ -372,6 +375,23 @@ pub fn FileInputDemo() -> impl IntoView {
                         </div>
                     </CardBody>
                 </Card>
+
+                <h2 class="text-xl font-semibold">"Bound File Input"</h2>
+                <FileInput files=selected_files attr:multiple=true />
+                <p class="text-sm">
+                    {move || format!("{} file(s) selected", selected_files.with(Vec::len))}
+                </p>
+
+                <h2 class="text-xl font-semibold">"File Drop Zone"</h2>
+                <FileDropZone
+                    files=dropped_files
+                    accept="image/*,.pdf"
+                    max_size=2 * 1024 * 1024
+                    max_count=3
+                >
+                    <span class="font-semibold">"Drop images or PDFs here"</span>
+                    <span class="text-sm opacity-60">"Up to 3 files, 2 MB each"</span>
+                </FileDropZone>
             </div>
         </div>
     }
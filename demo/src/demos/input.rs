@@ -1,6 +1,7 @@
 use crate::core::{ContentLayout, Section};
 use leptos::prelude::*;
 use leptos_daisyui_rs::components::*;
+use leptos_icons::Icon;
 
 #[component]
 pub fn InputDemo() -> impl IntoView {
@@ -77,6 +78,29 @@ pub fn InputDemo() -> impl IntoView {
                     />
                 </div>
             </Section>
+            <Section title="Input Group">
+                <div class="space-y-2">
+                    <InputGroup
+                        placeholder="Search"
+                        prefix=|| view! { <Icon icon=icondata::AiSearchOutlined /> }
+                        kbd=["⌘", "K"]
+                        clearable=true
+                        class="w-full max-w-xs"
+                    />
+                    <InputGroup
+                        password=true
+                        placeholder="Password"
+                        prefix=|| view! { <Icon icon=icondata::AiLockOutlined /> }
+                        class="w-full max-w-xs"
+                    />
+                    <InputGroup
+                        placeholder="Username"
+                        maxlength=20
+                        suffix=|| view! { <span class="opacity-60">"@example.com"</span> }
+                        class="w-full max-w-xs"
+                    />
+                </div>
+            </Section>
         </ContentLayout>
     }
 }
@@ -1,8 +1,11 @@
 use leptos::prelude::*;
-use leptos_daisyui_rs::components::*;
+use leptos_daisyui_rs::{components::*, utils::use_hotkey};
 
 #[component]
 pub fn KbdDemo() -> impl IntoView {
+    let presses = RwSignal::new(0);
+    use_hotkey("Mod+K", move |_| presses.update(|presses| *presses += 1));
+
     view! {
         <div class="space-y-6">
             <h1 class="text-3xl font-bold">"Kbd"</h1>
@@ -149,6 +152,18 @@ pub fn KbdDemo() -> impl IntoView {
                     </CardBody>
                 </Card>
 
+                <h2 class="text-xl font-semibold">"Shortcuts"</h2>
+                <div class="flex gap-4 flex-wrap items-center">
+                    <Shortcut keys="Mod+K" />
+                    <Shortcut keys="Mod+Shift+P" size=KbdSize::Sm />
+                    <Shortcut keys="Ctrl+Alt+Delete" size=KbdSize::Sm />
+                    <Shortcut keys="Shift+Enter" size=KbdSize::Xs />
+                </div>
+                <p class="text-sm">
+                    "Press " <Shortcut keys="Mod+K" size=KbdSize::Xs /> " outside of inputs: "
+                    {move || presses.get()} " times"
+                </p>
+
                 <h2 class="text-xl font-semibold">"Platform Specific"</h2>
                 <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
                     <Card class="bg-base-100 shadow-lg">
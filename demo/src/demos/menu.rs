@@ -7,6 +7,18 @@ use leptos_icons::Icon;
 pub fn MenuDemo() -> impl IntoView {
     let selected = RwSignal::new(None::<String>);
     let _manual_selected = RwSignal::new(None::<String>);
+    let tree_selected = RwSignal::new(Some("setup".to_string()));
+    let tree_nodes = vec![
+        MenuNode::new("Dashboard", "dashboard"),
+        MenuNode::new("Docs", "docs").children(vec![
+            MenuNode::new("Introduction", "intro"),
+            MenuNode::new("Guides", "guides").children(vec![
+                MenuNode::new("Setup", "setup"),
+                MenuNode::new("Theming", "theming").badge("new"),
+            ]),
+        ]),
+        MenuNode::new("Billing", "billing").disabled(),
+    ];
 
     view! {
         <ContentLayout
@@ -169,6 +181,13 @@ pub fn MenuDemo() -> impl IntoView {
                     </div>
                 </div>
             </Section>
+            <Section title="Menu Tree">
+                <MenuTree
+                    nodes=tree_nodes
+                    selected=tree_selected
+                    class="bg-base-200 rounded-box w-56"
+                />
+            </Section>
         </ContentLayout>
     }
 }
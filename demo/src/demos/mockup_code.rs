@@ -4,6 +4,9 @@ use leptos_daisyui_rs::components::*;
 
 #[component]
 pub fn MockupCodeDemo() -> impl IntoView {
+    let rust_source = "use leptos::prelude::*;\n\n#[component]\npub fn Counter() -> impl IntoView {\n    let count = RwSignal::new(0); // starts at zero\n    view! { <button on:click=move |_| *count.write() += 1>{count}</button> }\n}";
+    let toml_source = "[dependencies]\nleptos = { version = \"0.8\", features = [\"csr\"] }\nleptos-daisyui-rs = \"0.0.4\" # components";
+
     view! {
         <ContentLayout
             title="Mockup Code"
@@ -90,6 +93,27 @@ pub fn MockupCodeDemo() -> impl IntoView {
                     <MockupCodeLine prefix="$">"# Fix the error and try again"</MockupCodeLine>
                 </MockupCode>
             </Section>
+
+            <Section title="Code Block">
+                <CodeBlock
+                    code=rust_source.to_string()
+                    language=CodeLanguage::Rust
+                    highlight_lines=vec![5]
+                />
+            </Section>
+
+            <Section title="Code Block with Prompts">
+                <CodeBlock
+                    code="$ cargo add leptos-daisyui-rs\n    Updating crates.io index\n$ trunk serve --open"
+                        .to_string()
+                    language=CodeLanguage::Shell
+                    prefix=CodePrefix::Prompt
+                />
+            </Section>
+
+            <Section title="TOML Code Block">
+                <CodeBlock code=toml_source.to_string() language=CodeLanguage::Toml />
+            </Section>
         </ContentLayout>
     }
 }
@@ -5,6 +5,8 @@ use leptos_daisyui_rs::components::*;
 pub fn PaginationDemo() -> impl IntoView {
     let current_page = RwSignal::new(3);
     let total_pages = 10;
+    let paginator_page = RwSignal::new(1);
+    let paginator_size = RwSignal::new(10);
 
     view! {
         <div class="space-y-6">
@@ -27,6 +29,23 @@ pub fn PaginationDemo() -> impl IntoView {
                     </Button>
                 </div>
 
+                <h2 class="text-xl font-semibold">"Paginator"</h2>
+                <Paginator
+                    current_page=paginator_page
+                    total_items=237
+                    page_size=paginator_size
+                    page_sizes=vec![10, 25, 50]
+                    show_edges=true
+                    show_jump=true
+                />
+                <p class="text-sm">
+                    {move || {
+                        let start = (paginator_page.get() - 1) * paginator_size.get() + 1;
+                        let end = (start + paginator_size.get() - 1).min(237);
+                        format!("Showing items {start}-{end} of 237")
+                    }}
+                </p>
+
                 <h2 class="text-xl font-semibold">"Numbered Pagination"</h2>
                 <div class="join">
                     <Button class="join-item" style=ButtonStyle::Outline>
@@ -13,6 +13,8 @@ pub fn RangeDemo() -> impl IntoView {
     }
     .into_any();
     let b = a.to_html();
+    let price_low = RwSignal::new(20.0);
+    let price_high = RwSignal::new(80.0);
 
     view! {
         <ContentLayout
@@ -20,6 +22,21 @@ pub fn RangeDemo() -> impl IntoView {
             description="Range slider is used to select a value by sliding a handle"
         >
             <Section title="Basic Range">"coming soon..."</Section>
+            <Section title="Range Slider" col=true>
+                <RangeSlider
+                    low=price_low
+                    high=price_high
+                    step=5.0
+                    ticks=5
+                    tick_labels=true
+                    tooltip=true
+                    color=RangeColor::Primary
+                    format=|value: f64| format!("${value}")
+                />
+                <p class="text-sm">
+                    {move || format!("Selected: ${} - ${}", price_low.get(), price_high.get())}
+                </p>
+            </Section>
             <code>{b}</code>
         </ContentLayout>
     }
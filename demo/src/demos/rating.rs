@@ -5,6 +5,8 @@ use leptos_daisyui_rs::components::*;
 pub fn RatingDemo() -> impl IntoView {
     let (rating1, set_rating1) = signal(3);
     let (rating2, set_rating2) = signal(4);
+    let rating3 = RwSignal::new(3.5);
+    let rating4 = RwSignal::new(7.0);
 
     view! {
         <div class="space-y-6">
@@ -241,6 +243,23 @@ pub fn RatingDemo() -> impl IntoView {
                     <span class="text-sm">{move || format!("{}/5 stars", rating1.get())}</span>
                 </div>
 
+                <h2 class="text-xl font-semibold">"Managed Rating"</h2>
+                <div class="flex flex-wrap items-center gap-4">
+                    <RatingInput value=rating3 half=true clearable=true size=RatingSize::Lg />
+                    <RatingInput value=rating3 half=true read_only=true />
+                    <span class="text-sm">{move || format!("{}/5 stars", rating3.get())}</span>
+                </div>
+                <div class="flex flex-wrap items-center gap-4">
+                    <RatingInput
+                        value=rating4
+                        max=10
+                        mask=MaskType::Heart
+                        item_class="bg-red-400"
+                        size=RatingSize::Sm
+                    />
+                    <span class="text-sm">{move || format!("{}/10 hearts", rating4.get())}</span>
+                </div>
+
                 <h2 class="text-xl font-semibold">"Heart Rating"</h2>
                 <div class="rating">
                     <input type="radio" name="rating-7" class="mask mask-heart bg-red-400" />
@@ -1,8 +1,45 @@
 use leptos::prelude::*;
 use leptos_daisyui_rs::components::*;
 
+#[derive(Clone, Debug, PartialEq)]
+struct Language {
+    name: &'static str,
+    kind: &'static str,
+    deprecated: bool,
+}
+
 #[component]
 pub fn SelectDemo() -> impl IntoView {
+    let languages = vec![
+        Language {
+            name: "Rust",
+            kind: "Compiled",
+            deprecated: false,
+        },
+        Language {
+            name: "Go",
+            kind: "Compiled",
+            deprecated: false,
+        },
+        Language {
+            name: "Python",
+            kind: "Interpreted",
+            deprecated: false,
+        },
+        Language {
+            name: "Ruby",
+            kind: "Interpreted",
+            deprecated: false,
+        },
+        Language {
+            name: "Perl 4",
+            kind: "Interpreted",
+            deprecated: true,
+        },
+    ];
+    let favorite = RwSignal::new(None::<Language>);
+    let known = RwSignal::new(Vec::<Language>::new());
+
     view! {
         <div class="space-y-6">
             <h1 class="text-3xl font-bold">"Select"</h1>
@@ -74,6 +111,42 @@ pub fn SelectDemo() -> impl IntoView {
                         <option>"Option 1"</option>
                     </Select>
                 </div>
+
+                <h2 class="text-xl font-semibold">"Typed Select"</h2>
+                <div class="space-y-2">
+                    <TypedSelect
+                        options=languages.clone()
+                        selected=favorite
+                        label=|language: Language| language.name.to_string()
+                        key=|language: Language| language.name.to_string()
+                        group=|language: Language| Some(language.kind.to_string())
+                        option_disabled=|language: Language| language.deprecated
+                        placeholder="Pick your favorite language"
+                        class="w-full max-w-xs"
+                    />
+                    <p class="text-sm">
+                        "Favorite: "
+                        {move || favorite.get().map(|language| language.name).unwrap_or("none")}
+                    </p>
+                    <TypedSelect
+                        options=languages
+                        selected=known
+                        label=|language: Language| language.name.to_string()
+                        key=|language: Language| language.name.to_string()
+                        class="w-full max-w-xs h-32"
+                    />
+                    <p class="text-sm">
+                        "Known: "
+                        {move || {
+                            known
+                                .get()
+                                .iter()
+                                .map(|language| language.name)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        }}
+                    </p>
+                </div>
             </div>
         </div>
     }
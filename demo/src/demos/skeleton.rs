@@ -24,6 +24,31 @@ pub fn SkeletonDemo() -> impl IntoView {
                 <h2 class="text-xl font-semibold">"Rectangle Skeleton"</h2>
                 <div class="skeleton h-32 w-full"></div>
 
+                <h2 class="text-xl font-semibold">"Presets"</h2>
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-6 items-start">
+                    <SkeletonText lines=4 />
+                    <div class="flex gap-2">
+                        <SkeletonAvatar size=SkeletonSize::Sm />
+                        <SkeletonAvatar />
+                        <SkeletonAvatar size=SkeletonSize::Lg square=true />
+                    </div>
+                    <SkeletonCard class="w-52" />
+                </div>
+
+                <h2 class="text-xl font-semibold">"Table Rows Preset"</h2>
+                <Table size=TableSize::Sm>
+                    <TableHead>
+                        <TableRow>
+                            <TableHeader>"Name"</TableHeader>
+                            <TableHeader>"Email"</TableHeader>
+                            <TableHeader>"Status"</TableHeader>
+                        </TableRow>
+                    </TableHead>
+                    <TableBody>
+                        <SkeletonTableRows rows=3 columns=3 size=TableSize::Sm />
+                    </TableBody>
+                </Table>
+
                 <h2 class="text-xl font-semibold">"Card Layout Skeleton"</h2>
                 <div class="flex flex-col gap-4">
                     <div class="flex items-center gap-4">
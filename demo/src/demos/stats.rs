@@ -5,6 +5,11 @@ use leptos_icons::Icon;
 
 #[component]
 pub fn StatsDemo() -> impl IntoView {
+    let revenue = RwSignal::new(48_250.0);
+    let previous_revenue = RwSignal::new(41_900.0);
+    let visitors = RwSignal::new(1_240_000.0);
+    let bounce_rate = RwSignal::new(0.412);
+
     view! {
         <ContentLayout
             title="Stats"
@@ -187,6 +192,40 @@ pub fn StatsDemo() -> impl IntoView {
                     </CardBody>
                 </Card>
             </Section>
+
+            <Section title="Animated Values">
+                <Stats class="shadow">
+                    <Stat>
+                        <StatTitle>"Revenue"</StatTitle>
+                        <StatNumber value=revenue format=StatFormat::Currency("$") />
+                        <StatDesc current=revenue previous=previous_revenue>
+                            "vs. last month"
+                        </StatDesc>
+                    </Stat>
+                    <Stat>
+                        <StatTitle>"Visitors"</StatTitle>
+                        <StatNumber value=visitors format=StatFormat::Compact />
+                        <StatDesc>"This year"</StatDesc>
+                    </Stat>
+                    <Stat>
+                        <StatTitle>"Bounce Rate"</StatTitle>
+                        <StatNumber value=bounce_rate format=StatFormat::Percent />
+                        <StatDesc current=bounce_rate previous=0.45 invert_trend=true />
+                    </Stat>
+                </Stats>
+                <Button
+                    size=ButtonSize::Sm
+                    class="mt-4"
+                    on:click=move |_| {
+                        previous_revenue.set(revenue.get_untracked());
+                        revenue.update(|revenue| *revenue *= 1.12);
+                        visitors.update(|visitors| *visitors += 35_000.0);
+                        bounce_rate.update(|rate| *rate -= 0.015);
+                    }
+                >
+                    "Refresh"
+                </Button>
+            </Section>
         </ContentLayout>
     }
 }
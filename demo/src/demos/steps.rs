@@ -4,6 +4,33 @@ use leptos_daisyui_rs::components::*;
 #[component]
 pub fn StepsDemo() -> impl IntoView {
     let (current_step, set_current_step) = signal(1);
+    let wizard_step = RwSignal::new(0);
+    let account_name = RwSignal::new(String::new());
+    let wizard_done = RwSignal::new(false);
+    let wizard_steps = vec![
+        WizardStep::new("Account", move || {
+            view! {
+                <input
+                    class="input"
+                    placeholder="Account name"
+                    bind:value=account_name
+                />
+            }
+        })
+        .validate(move || async move {
+            if account_name.get_untracked().trim().len() >= 3 {
+                Ok(())
+            } else {
+                Err("Account name must be at least 3 characters".to_string())
+            }
+        }),
+        WizardStep::new(
+            "Profile",
+            || view! { <p>"Tell us about yourself (optional)."</p> },
+        )
+        .skippable(),
+        WizardStep::new("Confirm", || view! { <p>"Everything looks good."</p> }),
+    ];
 
     view! {
         <div class="space-y-6">
@@ -336,6 +363,16 @@ pub fn StepsDemo() -> impl IntoView {
                         </div>
                     </CardBody>
                 </Card>
+
+                <h2 class="text-xl font-semibold">"Wizard"</h2>
+                <Wizard
+                    steps=wizard_steps
+                    current_step=wizard_step
+                    on_finish=move |_| wizard_done.set(true)
+                />
+                <Show when=move || wizard_done.get()>
+                    <p class="text-success">"Wizard finished!"</p>
+                </Show>
             </div>
         </div>
     }
@@ -1,6 +1,68 @@
+use crate::core::{ContentLayout, Section};
 use leptos::prelude::*;
+use leptos_daisyui_rs::components::*;
 
 #[component]
 pub fn SwapDemo() -> impl IntoView {
-    view! { <div>"Swap demo - coming soon"</div> }
+    let menu_open = RwSignal::new(false);
+    let muted = RwSignal::new(true);
+    let menu_toggles = RwSignal::new(0);
+
+    view! {
+        <ContentLayout title="Swap" description="Swap allows you to toggle the visibility of two elements">
+            <Section title="Text Swap">
+                <Swap>
+                    <SwapOn>"ON"</SwapOn>
+                    <SwapOff>"OFF"</SwapOff>
+                </Swap>
+            </Section>
+
+            <Section title="Presets">
+                <div class="flex gap-4 items-center">
+                    <Swap
+                        preset=SwapPreset::HamburgerClose
+                        rotate=SwapRotate::Rotate
+                        active=menu_open
+                    />
+                    <Swap preset=SwapPreset::MoonSun rotate=SwapRotate::Rotate />
+                    <Swap preset=SwapPreset::Volume active=muted />
+                </div>
+            </Section>
+
+            <Section title="Bound State">
+                <div class="flex gap-4 items-center">
+                    <Swap
+                        preset=SwapPreset::HamburgerClose
+                        rotate=SwapRotate::Rotate
+                        active=menu_open
+                        on_toggle=move |_: bool| menu_toggles.update(|count| *count += 1)
+                    />
+                    <span>{move || if menu_open.get() { "Menu open" } else { "Menu closed" }}</span>
+                    <span class="text-sm">
+                        {move || format!("Toggled {} times", menu_toggles.get())}
+                    </span>
+                    <button class="btn btn-sm" on:click=move |_| menu_open.set(false)>
+                        "Close"
+                    </button>
+                </div>
+            </Section>
+
+            <Section title="Flip">
+                <Swap rotate=SwapRotate::Flip class="text-6xl">
+                    <SwapOn>"😈"</SwapOn>
+                    <SwapOff>"😇"</SwapOff>
+                </Swap>
+            </Section>
+
+            <Section title="In a Form">
+                <form class="flex gap-2 items-center">
+                    <Swap name="notifications" value="enabled">
+                        <SwapOn>"🔔"</SwapOn>
+                        <SwapOff>"🔕"</SwapOff>
+                    </Swap>
+                    <span class="text-sm">"Submits notifications=enabled while active"</span>
+                </form>
+            </Section>
+        </ContentLayout>
+    }
 }
@@ -5,6 +5,15 @@ use leptos_icons::Icon;
 
 #[component]
 pub fn TimelineDemo() -> impl IntoView {
+    let milestones = RwSignal::new(vec![
+        ("1984", "First Macintosh computer", true),
+        ("1998", "iMac", true),
+        ("2001", "iPod", true),
+        ("2007", "iPhone", false),
+        ("2015", "Apple Watch", false),
+    ]);
+    let compact = RwSignal::new(false);
+
     view! {
         <ContentLayout
             title="Timeline"
@@ -133,6 +142,43 @@ pub fn TimelineDemo() -> impl IntoView {
                     </TimelineItem>
                 </Timeline>
             </Section>
+
+            <Section title="Data-driven Timeline">
+                <div class="flex gap-2 mb-4">
+                    <Button
+                        size=ButtonSize::Sm
+                        on:click=move |_| {
+                            milestones
+                                .update(|milestones| {
+                                    if let Some(next) = milestones.iter_mut().find(|m| !m.2) {
+                                        next.2 = true;
+                                    }
+                                })
+                        }
+                    >
+                        "Complete next"
+                    </Button>
+                    <Button size=ButtonSize::Sm on:click=move |_| compact.update(|c| *c = !*c)>
+                        "Toggle compact"
+                    </Button>
+                </div>
+                <TimelineList
+                    events=milestones
+                    key=|(year, _, _): (&'static str, &'static str, bool)| year.to_string()
+                    start=|(year, _, _): (&'static str, &'static str, bool)| year.into_any()
+                    middle=|(_, _, done): (&'static str, &'static str, bool)| {
+                        let class = if done { "fill-primary" } else { "fill-base-300" };
+                        view! { <Icon icon=icondata::AiCheckCircleFilled attr:class=class /> }
+                            .into_any()
+                    }
+                    end=|(_, title, _): (&'static str, &'static str, bool)| title.into_any()
+                    completed=|(_, _, done): (&'static str, &'static str, bool)| done
+                    alternate=true
+                    boxed=true
+                    compact=compact
+                    direction=TimelineDirection::Vertical
+                />
+            </Section>
         </ContentLayout>
     }
 }
@@ -0,0 +1,305 @@
+use crate::component::CargoRequirements;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+/// Versions of the crates generated sources import directly
+const CRATES: &[(&str, &str)] = &[("hydration_context", "0.3")];
+
+/// Optional dependencies enabled by the Cargo features generated sources check
+const FEATURES: &[(&str, &[(&str, &str)])] = &[
+    ("icons", &[("leptos_icons", "0.7"), ("icondata", "0.7")]),
+    ("router", &[("leptos_router", "0.8")]),
+];
+
+/// Version of `web-sys` added when a component needs features leptos doesn't enable
+const WEB_SYS_VERSION: &str = "0.3";
+
+pub struct CargoManager;
+
+impl CargoManager {
+    /// Add the dependencies and features in `requirements` missing from a Cargo.toml.
+    ///
+    /// Entries are appended to the existing tables, leaving the rest of the file as is.
+    /// Returns whether the file was changed.
+    pub fn apply(cargo_toml: &Path, requirements: &CargoRequirements) -> Result<bool> {
+        let original = fs::read_to_string(cargo_toml)
+            .with_context(|| format!("Failed to read {}", cargo_toml.display()))?;
+        let mut content = original.clone();
+
+        for name in &requirements.crates {
+            Self::add_crate(&mut content, name)?;
+        }
+        for feature in &requirements.features {
+            Self::add_feature(&mut content, feature)?;
+        }
+        if !requirements.web_sys_features.is_empty() {
+            Self::add_web_sys_features(&mut content, &requirements.web_sys_features)?;
+        }
+
+        if content == original {
+            return Ok(false);
+        }
+
+        fs::write(cargo_toml, content)
+            .with_context(|| format!("Failed to write {}", cargo_toml.display()))?;
+
+        Ok(true)
+    }
+
+    /// Add a dependency on a crate unless there is one already
+    fn add_crate(content: &mut String, name: &str) -> Result<()> {
+        let (_, version) = CRATES
+            .iter()
+            .find(|(crate_name, _)| *crate_name == name)
+            .with_context(|| format!("Unknown crate: {}", name))?;
+
+        if dependency(&parse(content)?, name).is_none() {
+            insert_into_table(
+                content,
+                "dependencies",
+                &format!("{} = \"{}\"", name, version),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Declare a Cargo feature unless it exists, adding the optional dependencies it enables
+    fn add_feature(content: &mut String, feature: &str) -> Result<()> {
+        let (_, dependencies) = FEATURES
+            .iter()
+            .find(|(name, _)| *name == feature)
+            .with_context(|| format!("Unknown feature: {}", feature))?;
+
+        let manifest = parse(content)?;
+        if manifest
+            .get("features")
+            .and_then(|features| features.get(feature))
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let mut enables = Vec::new();
+        for (name, version) in dependencies.iter() {
+            match dependency(&manifest, name) {
+                // A regular dependency is always available, so the feature only gates code
+                Some(existing) if !is_optional(existing) => {}
+                Some(_) => enables.push(format!("\"dep:{}\"", name)),
+                None => {
+                    insert_into_table(
+                        content,
+                        "dependencies",
+                        &format!(
+                            "{} = {{ version = \"{}\", optional = true }}",
+                            name, version
+                        ),
+                    );
+                    enables.push(format!("\"dep:{}\"", name));
+                }
+            }
+        }
+
+        insert_into_table(
+            content,
+            "features",
+            &format!("{} = [{}]", feature, enables.join(", ")),
+        );
+
+        Ok(())
+    }
+
+    /// Enable `web-sys` features, adding the dependency if needed
+    fn add_web_sys_features(content: &mut String, features: &[String]) -> Result<()> {
+        let manifest = parse(content)?;
+        let Some(web_sys) = dependency(&manifest, "web-sys") else {
+            insert_into_table(
+                content,
+                "dependencies",
+                &format!(
+                    "web-sys = {{ version = \"{}\", features = {} }}",
+                    WEB_SYS_VERSION,
+                    feature_list(features)
+                ),
+            );
+            return Ok(());
+        };
+
+        let mut enabled = web_sys
+            .get("features")
+            .and_then(|enabled| enabled.as_array())
+            .map(|enabled| {
+                enabled
+                    .iter()
+                    .filter_map(|feature| feature.as_str().map(str::to_string))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let missing = features
+            .iter()
+            .filter(|feature| !enabled.contains(feature))
+            .cloned()
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        enabled.extend(missing.iter().cloned());
+
+        let entry = find_entry(content, "dependencies", "web-sys").and_then(|range| {
+            rewrite_features(&content[range.clone()], web_sys, &enabled).map(|line| (range, line))
+        });
+
+        match entry {
+            Some((range, line)) => content.replace_range(range, &line),
+            None => {
+                println!(
+                    "  {} Enable these web-sys features in your Cargo.toml: {}",
+                    "!".yellow(),
+                    missing.join(", ").cyan()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse(content: &str) -> Result<toml::Value> {
+    toml::from_str(content).context("Failed to parse Cargo.toml")
+}
+
+/// Entry of a crate in `[dependencies]`
+fn dependency<'a>(manifest: &'a toml::Value, name: &str) -> Option<&'a toml::Value> {
+    manifest
+        .get("dependencies")
+        .and_then(|dependencies| dependencies.get(name))
+}
+
+fn is_optional(dependency: &toml::Value) -> bool {
+    dependency
+        .get("optional")
+        .and_then(|optional| optional.as_bool())
+        .unwrap_or(false)
+}
+
+/// Format features as a TOML array
+fn feature_list(features: &[String]) -> String {
+    let quoted = features
+        .iter()
+        .map(|feature| format!("\"{}\"", feature))
+        .collect::<Vec<_>>();
+    format!("[{}]", quoted.join(", "))
+}
+
+/// Rewrite a one-line `web-sys` entry to enable `features`, or `None` if it spans lines
+fn rewrite_features(line: &str, entry: &toml::Value, features: &[String]) -> Option<String> {
+    if let Some(version) = entry.as_str() {
+        return Some(format!(
+            "web-sys = {{ version = \"{}\", features = {} }}",
+            version,
+            feature_list(features)
+        ));
+    }
+
+    let line = line.trim_end();
+    if !line.ends_with('}') {
+        return None;
+    }
+
+    // `features` itself, not a key like `default-features`
+    let features_key = line
+        .match_indices("features")
+        .map(|(index, _)| index)
+        .find(|index| {
+            !line[..*index].ends_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_')
+        });
+
+    match features_key {
+        Some(start) => {
+            let open = start + line[start..].find('[')?;
+            let close = open + line[open..].find(']')?;
+            Some(format!(
+                "{}{}{}",
+                &line[..open],
+                feature_list(features),
+                &line[close + 1..]
+            ))
+        }
+        None => {
+            let body = line[..line.len() - 1].trim_end();
+            Some(format!(
+                "{}, features = {} }}",
+                body,
+                feature_list(features)
+            ))
+        }
+    }
+}
+
+/// Byte range of the line holding `key` in `[table]`, without its line break
+fn find_entry(content: &str, table: &str, key: &str) -> Option<Range<usize>> {
+    let header = format!("[{}]", table);
+    let mut in_table = false;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') {
+            in_table = trimmed == header;
+        } else if in_table
+            && let Some(rest) = trimmed.strip_prefix(key)
+            && rest.trim_start().starts_with('=')
+        {
+            let end = start + line.trim_end_matches(['\r', '\n']).len();
+            return Some(start..end);
+        }
+    }
+
+    None
+}
+
+/// Insert a line at the end of `[table]`, creating the table if it doesn't exist
+fn insert_into_table(content: &mut String, table: &str, line: &str) {
+    let header = format!("[{}]", table);
+    let mut insert_at = None;
+    let mut offset = 0;
+
+    for current in content.split_inclusive('\n') {
+        offset += current.len();
+        let trimmed = current.trim();
+
+        if insert_at.is_some() {
+            if trimmed.starts_with('[') {
+                break;
+            }
+            if !trimmed.is_empty() {
+                insert_at = Some(offset);
+            }
+        } else if trimmed == header {
+            insert_at = Some(offset);
+        }
+    }
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+
+    match insert_at {
+        Some(position) => {
+            let position = position.min(content.len());
+            let position = if content[..position].ends_with('\n') {
+                position
+            } else {
+                position + 1
+            };
+            content.insert_str(position, &format!("{}\n", line));
+        }
+        None => content.push_str(&format!("\n{}\n{}\n", header, line)),
+    }
+}
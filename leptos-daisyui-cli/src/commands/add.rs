@@ -1,4 +1,5 @@
-use crate::component::{ComponentGenerator, ComponentRegistry};
+use crate::cargo::CargoManager;
+use crate::component::{CargoRequirements, ComponentGenerator, ComponentRegistry};
 use crate::css::CssManager;
 use crate::project::ProjectDetector;
 use anyhow::{Context, Result};
@@ -14,6 +15,7 @@ pub fn execute(components: Vec<String>, force: bool, _interactive: bool) -> Resu
 
     let src_dir = ProjectDetector::find_main_binary_crate(&project)?;
     let generated_dir = src_dir.join("generated");
+    let cargo_toml = ProjectDetector::find_main_binary_manifest(&project)?;
 
     // Ensure generated directory exists
     if !generated_dir.exists() {
@@ -25,7 +27,14 @@ pub fn execute(components: Vec<String>, force: bool, _interactive: bool) -> Resu
 
     // Process each component
     for component_name in &components {
-        add_component(&registry, &generated_dir, &project, component_name, force)?;
+        add_component(
+            &registry,
+            &generated_dir,
+            &cargo_toml,
+            &project,
+            component_name,
+            force,
+        )?;
     }
 
     println!();
@@ -51,6 +60,7 @@ pub fn execute(components: Vec<String>, force: bool, _interactive: bool) -> Resu
 fn add_component(
     registry: &ComponentRegistry,
     generated_dir: &std::path::Path,
+    cargo_toml: &std::path::Path,
     project: &crate::project::ProjectStructure,
     component_name: &str,
     force: bool,
@@ -80,6 +90,26 @@ fn add_component(
 
     println!("  {} Created component files", "✓".green());
 
+    // Add the crates and features the component sources need to Cargo.toml
+    add_cargo_requirements(cargo_toml, &metadata.cargo)?;
+
+    // Copy utility modules the component imports
+    for util_name in &metadata.utils {
+        add_util(registry, generated_dir, cargo_toml, util_name)?;
+    }
+
+    // Add components the component imports
+    for dependency in &metadata.dependencies {
+        add_component(
+            registry,
+            generated_dir,
+            cargo_toml,
+            project,
+            dependency,
+            false,
+        )?;
+    }
+
     // Update generated/mod.rs
     update_generated_mod(generated_dir, &metadata.name)?;
     println!("  {} Updated generated/mod.rs", "✓".green());
@@ -92,6 +122,49 @@ fn add_component(
     Ok(())
 }
 
+fn add_util(
+    registry: &ComponentRegistry,
+    generated_dir: &std::path::Path,
+    cargo_toml: &std::path::Path,
+    util_name: &str,
+) -> Result<()> {
+    ComponentGenerator::write_util(util_name, generated_dir)
+        .with_context(|| format!("Failed to generate utility '{}'", util_name))?;
+
+    if let Some(util) = registry.get_util(util_name) {
+        add_cargo_requirements(cargo_toml, &util.cargo)?;
+    }
+
+    // Register the module in generated/utils.rs
+    let utils_rs = generated_dir.join("utils.rs");
+    let mut content = fs::read_to_string(&utils_rs)
+        .with_context(|| format!("Failed to read {}", utils_rs.display()))?;
+
+    let mod_line = format!("mod {};", util_name);
+    let use_line = format!("pub use {}::*;", util_name);
+
+    if !content.contains(&mod_line) {
+        content.push_str(&format!("\n{}\n{}\n", mod_line, use_line));
+        fs::write(&utils_rs, content)
+            .with_context(|| format!("Failed to write {}", utils_rs.display()))?;
+    }
+
+    println!("  {} Added utils/{}.rs", "✓".green(), util_name);
+
+    Ok(())
+}
+
+fn add_cargo_requirements(
+    cargo_toml: &std::path::Path,
+    requirements: &CargoRequirements,
+) -> Result<()> {
+    if CargoManager::apply(cargo_toml, requirements)? {
+        println!("  {} Updated Cargo.toml", "✓".green());
+    }
+
+    Ok(())
+}
+
 fn update_generated_mod(generated_dir: &std::path::Path, component_name: &str) -> Result<()> {
     let mod_rs = generated_dir.join("mod.rs");
     let mut content = fs::read_to_string(&mod_rs)
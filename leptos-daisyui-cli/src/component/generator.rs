@@ -6,6 +6,10 @@ use std::path::Path;
 #[folder = "../leptos-daisyui-rs/src/components"]
 struct ComponentSources;
 
+#[derive(Embed)]
+#[folder = "../leptos-daisyui-rs/src/utils"]
+struct UtilSources;
+
 pub struct ComponentFiles {
     pub mod_rs: String,
     pub component_rs: String,
@@ -32,6 +36,18 @@ impl ComponentGenerator {
         })
     }
 
+    /// Get the source of a utility module from embedded resources
+    pub fn get_util_file(util_name: &str) -> Result<String> {
+        let path = format!("{}.rs", util_name);
+        let file =
+            UtilSources::get(&path).with_context(|| format!("Utility file not found: {}", path))?;
+
+        let content = std::str::from_utf8(file.data.as_ref())
+            .with_context(|| format!("Invalid UTF-8 in file: {}", path))?;
+
+        Ok(content.to_string())
+    }
+
     /// Read an embedded file and convert to String
     fn read_embedded_file(path: &str) -> Result<String> {
         let file = ComponentSources::get(path)
@@ -55,7 +71,8 @@ impl ComponentGenerator {
         // Write mod.rs
         std::fs::write(component_dir.join("mod.rs"), files.mod_rs)?;
 
-        // Write component.rs (no path fix needed - macro is exported at crate root)
+        // Write component.rs (no path fix needed - macro is exported at crate root and
+        // utilities are imported through super::super::utils)
         std::fs::write(component_dir.join("component.rs"), files.component_rs)?;
 
         // Write style.rs if present
@@ -65,4 +82,17 @@ impl ComponentGenerator {
 
         Ok(())
     }
+
+    /// Write a utility module to `utils/` next to `utils.rs` in the target directory
+    pub fn write_util(util_name: &str, target_dir: &Path) -> Result<()> {
+        let util_rs = Self::get_util_file(util_name)?;
+        let utils_dir = target_dir.join("utils");
+
+        std::fs::create_dir_all(&utils_dir)
+            .with_context(|| format!("Failed to create directory: {}", utils_dir.display()))?;
+
+        std::fs::write(utils_dir.join(format!("{}.rs", util_name)), util_rs)?;
+
+        Ok(())
+    }
 }
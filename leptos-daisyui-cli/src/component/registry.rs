@@ -11,12 +11,46 @@ pub struct ComponentMetadata {
     pub css_classes: String,
     pub daisyui_url: String,
     pub category: String,
+    /// Other components whose sources this component imports
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Utility modules from `leptos-daisyui-rs/src/utils` this component imports
+    #[serde(default)]
+    pub utils: Vec<String>,
+    /// Cargo dependencies and features the component sources need
+    #[serde(flatten)]
+    pub cargo: CargoRequirements,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UtilMetadata {
+    pub name: String,
+    /// Cargo dependencies and features the utility module needs
+    #[serde(flatten)]
+    pub cargo: CargoRequirements,
+}
+
+/// What generated sources need in the user's Cargo.toml beyond leptos
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CargoRequirements {
+    /// Crates imported directly
+    #[serde(default)]
+    pub crates: Vec<String>,
+    /// Cargo features checked with `cfg(feature = "...")`
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// `web-sys` features not already enabled by leptos
+    #[serde(default)]
+    pub web_sys_features: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ComponentRegistry {
     pub version: String,
     pub components: Vec<ComponentMetadata>,
+    /// Utility modules with Cargo requirements
+    #[serde(default)]
+    pub utils: Vec<UtilMetadata>,
     pub categories: Vec<String>,
 }
 
@@ -38,6 +72,11 @@ impl ComponentRegistry {
         self.components.iter().find(|c| c.name == name)
     }
 
+    /// Get utility module by name
+    pub fn get_util(&self, name: &str) -> Option<&UtilMetadata> {
+        self.utils.iter().find(|u| u.name == name)
+    }
+
     /// Get components by category
     pub fn get_by_category(&self, category: &str) -> Vec<&ComponentMetadata> {
         self.components
@@ -1,3 +1,4 @@
+pub mod cargo;
 pub mod commands;
 pub mod component;
 pub mod css;
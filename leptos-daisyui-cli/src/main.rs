@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand};
 
+mod cargo;
 mod commands;
 mod component;
 mod css;
@@ -164,4 +164,16 @@ impl ProjectDetector {
             }
         }
     }
+
+    /// Find the Cargo.toml of the main binary crate in the project
+    pub fn find_main_binary_manifest(structure: &ProjectStructure) -> Result<PathBuf> {
+        match structure {
+            ProjectStructure::Monorepo { cargo_toml, .. } => Ok(cargo_toml.clone()),
+            ProjectStructure::Workspace { members, .. } => members
+                .iter()
+                .find(|m| m.is_binary)
+                .map(|m| m.cargo_toml.clone())
+                .ok_or_else(|| anyhow!("No binary crate found in workspace")),
+        }
+    }
 }
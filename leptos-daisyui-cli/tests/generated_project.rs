@@ -0,0 +1,112 @@
+//! Adds every component to a fresh project and checks that the result compiles
+
+use leptos_daisyui_cli::component::ComponentRegistry;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const MANIFEST: &str = r#"[package]
+name = "generated-project"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+leptos = { version = "0.8", features = ["csr"] }
+"#;
+
+const MOD_RS: &str = "pub mod utils;\npub use utils::*;\n";
+
+/// Create a project like `leptos-daisyui init` does and add all components to it
+fn generate_project(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("leptos-daisyui-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    let generated_dir = root.join("src/generated");
+    fs::create_dir_all(&generated_dir).unwrap();
+    fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+    fs::write(root.join("input.css"), "@import \"tailwindcss\";\n").unwrap();
+    fs::write(
+        root.join("src/main.rs"),
+        "pub mod generated;\n\nfn main() {}\n",
+    )
+    .unwrap();
+    fs::write(generated_dir.join("mod.rs"), MOD_RS).unwrap();
+    fs::write(
+        generated_dir.join("utils.rs"),
+        include_str!("../assets/utils.rs"),
+    )
+    .unwrap();
+
+    let components = ComponentRegistry::load()
+        .unwrap()
+        .all()
+        .iter()
+        .map(|c| c.name.clone())
+        .collect::<Vec<_>>();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_leptos-daisyui"))
+        .arg("add")
+        .args(&components)
+        .current_dir(&root)
+        .status()
+        .unwrap();
+    assert!(status.success(), "leptos-daisyui add failed");
+
+    root
+}
+
+fn cargo_check(root: &Path, args: &[&str]) {
+    let status = Command::new(env!("CARGO"))
+        .arg("check")
+        .args(args)
+        .current_dir(root)
+        .status()
+        .unwrap();
+    assert!(status.success(), "cargo check {:?} failed", args);
+}
+
+#[test]
+fn adds_cargo_requirements() {
+    let root = generate_project("requirements");
+    let manifest: toml::Value =
+        toml::from_str(&fs::read_to_string(root.join("Cargo.toml")).unwrap()).unwrap();
+
+    let dependencies = &manifest["dependencies"];
+    assert!(dependencies.get("hydration_context").is_some());
+    assert_eq!(
+        dependencies["leptos_router"]["optional"].as_bool(),
+        Some(true)
+    );
+
+    let web_sys_features = dependencies["web-sys"]["features"].as_array().unwrap();
+    for feature in [
+        "Clipboard",
+        "DomRect",
+        "File",
+        "Navigator",
+        "NodeList",
+        "Url",
+    ] {
+        assert!(
+            web_sys_features.iter().any(|f| f.as_str() == Some(feature)),
+            "web-sys feature {} missing",
+            feature
+        );
+    }
+
+    let features = &manifest["features"];
+    assert!(features.get("icons").is_some());
+    assert!(features.get("router").is_some());
+
+    assert!(root.join("src/generated/utils/id.rs").exists());
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+#[ignore = "downloads and compiles leptos"]
+fn generated_project_compiles() {
+    let root = generate_project("compiles");
+    cargo_check(&root, &[]);
+    cargo_check(&root, &["--all-features"]);
+    let _ = fs::remove_dir_all(root);
+}
@@ -1,7 +1,11 @@
-use super::style::{AccordionForceModifier, AccordionModifier};
+use super::{
+    super::utils::unique_id,
+    style::{AccordionForceModifier, AccordionModifier},
+};
 use crate::merge_classes;
 use leptos::{
     html::{Div, Input},
+    logging,
     prelude::*,
 };
 
@@ -11,6 +15,9 @@ use leptos::{
 /// When multiple accordions share the same radio input name, only one can be open at a time, creating traditional
 /// accordion behavior.
 ///
+/// Inside an [`AccordionGroup`], the group manages which sections are open and `name`
+/// and `checked` are ignored. Each section in a group needs its own `index`.
+///
 /// ### Add to `input.css`
 /// ```css
 /// @source inline("collapse collapse-title collapse-content collapse-arrow collapse-plus collapse-open collapse-close");
@@ -36,10 +43,17 @@ pub fn Accordion(
     #[prop(optional, into)]
     force: Signal<AccordionForceModifier>,
 
-    /// Visual modifier for the accordion appearance
+    /// Visual modifier for the accordion appearance, overriding the group's modifier
     #[prop(optional, into)]
     modifier: Signal<AccordionModifier>,
 
+    /// Index identifying the section within an [`AccordionGroup`]
+    ///
+    /// Required inside a group, where `open` refers to sections by this index. Sections
+    /// without one are not managed by the group.
+    #[prop(optional)]
+    index: Option<usize>,
+
     /// Additional CSS classes to apply to the accordion container
     #[prop(optional, into)]
     class: &'static str,
@@ -55,6 +69,40 @@ pub fn Accordion(
     /// Child components, typically [`AccordionTitle`] and [`AccordionContent`]
     children: Children,
 ) -> impl IntoView {
+    let group = use_context::<AccordionGroupManager>();
+    if group.is_some() && index.is_none() {
+        logging::warn!("Accordion inside an AccordionGroup needs an `index` to be managed");
+    }
+
+    if let (Some(group), Some(index)) = (group, index) {
+        let AccordionGroupManager { name, open, .. } = group.clone();
+        let group_modifier = group.modifier;
+
+        return view! {
+            <div
+                node_ref=outer_node_ref
+                class=move || {
+                    let modifier = match modifier.get() {
+                        AccordionModifier::Default => group_modifier.get(),
+                        modifier => modifier,
+                    };
+                    merge_classes!("collapse", modifier.as_str(), force.get().as_str(), class)
+                }
+            >
+                <input
+                    node_ref=inner_node_ref
+                    type="checkbox"
+                    name=name
+                    checked=move || open.with(|open| open.contains(&index))
+                    prop:checked=move || open.with(|open| open.contains(&index))
+                    on:change=move |_| group.toggle(index)
+                />
+                {children()}
+            </div>
+        }
+        .into_any();
+    }
+
     view! {
         <div
             node_ref=outer_node_ref
@@ -71,6 +119,107 @@ pub fn Accordion(
             {children()}
         </div>
     }
+    .into_any()
+}
+
+/// # Accordion Group Component
+///
+/// Manages a set of [`Accordion`]s: tracks which sections are open in `open` by each
+/// section's `index`, keeps only one open unless `multiple` is set, and applies
+/// `modifier` to every section. The sections share a generated input name.
+///
+/// Indices are given explicitly so that `open` keeps pointing at the same sections when
+/// some of them are rendered conditionally or re-rendered.
+///
+/// ## Node References
+/// - `node_ref` - References the group `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn AccordionGroup(
+    /// Indices of the open sections
+    #[prop(optional)]
+    open: RwSignal<Vec<usize>>,
+
+    /// Whether several sections can be open at once
+    #[prop(optional, into)]
+    multiple: Signal<bool>,
+
+    /// Visual modifier applied to every section
+    #[prop(optional, into)]
+    modifier: Signal<AccordionModifier>,
+
+    /// Called with the open indices after the user toggles a section
+    #[prop(optional, into)]
+    on_change: Option<Callback<Vec<usize>>>,
+
+    /// Additional CSS classes to apply to the group container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the group `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+
+    /// Child [`Accordion`] components
+    children: Children,
+) -> impl IntoView {
+    provide_context(AccordionGroupManager {
+        name: unique_id("accordion"),
+        open,
+        multiple,
+        modifier,
+        on_change,
+    });
+
+    view! {
+        <div node_ref=node_ref class=class>
+            {children()}
+        </div>
+    }
+}
+
+/// Internal context manager for accordion group state.
+#[derive(Clone)]
+pub(crate) struct AccordionGroupManager {
+    /// Input name shared by the group's sections
+    name: String,
+    /// Indices of the open sections
+    open: RwSignal<Vec<usize>>,
+    /// Whether several sections can be open at once
+    multiple: Signal<bool>,
+    /// Modifier applied to sections without their own
+    modifier: Signal<AccordionModifier>,
+    /// Change notification callback
+    on_change: Option<Callback<Vec<usize>>>,
+}
+
+impl AccordionGroupManager {
+    fn toggle(&self, index: usize) {
+        let multiple = self.multiple.get_untracked();
+        let open = self
+            .open
+            .with_untracked(|open| toggle_open(open, index, multiple));
+
+        self.open.set(open.clone());
+        if let Some(on_change) = self.on_change {
+            on_change.run(open);
+        }
+    }
+}
+
+/// Opens or closes `index`, closing the other sections unless `multiple`
+pub(crate) fn toggle_open(open: &[usize], index: usize, multiple: bool) -> Vec<usize> {
+    if open.contains(&index) {
+        return open.iter().copied().filter(|&i| i != index).collect();
+    }
+
+    if !multiple {
+        return vec![index];
+    }
+
+    let mut open = open.to_vec();
+    open.push(index);
+    open.sort_unstable();
+    open
 }
 
 /// # Accordion Title Component
@@ -126,3 +275,20 @@ pub fn AccordionContent(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_open_single_keeps_one_section() {
+        assert_eq!(toggle_open(&[0], 2, false), vec![2]);
+        assert_eq!(toggle_open(&[2], 2, false), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn toggle_open_multiple_adds_and_removes() {
+        assert_eq!(toggle_open(&[3], 1, true), vec![1, 3]);
+        assert_eq!(toggle_open(&[1, 3], 3, true), vec![1]);
+    }
+}
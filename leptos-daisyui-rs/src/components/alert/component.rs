@@ -1,4 +1,7 @@
-use super::style::{AlertColor, AlertDirection, AlertStyle};
+use super::{
+    super::utils::{IconSlot, icon_slot},
+    style::{AlertColor, AlertDirection, AlertStyle},
+};
 use crate::merge_classes;
 use leptos::{html::Div, prelude::*};
 
@@ -7,6 +10,9 @@ use leptos::{html::Div, prelude::*};
 /// A reactive Leptos wrapper for daisyUI's alert component that displays important messages,
 /// notifications, and contextual feedback to users.
 ///
+/// With the `icons` feature enabled, `show_icon` adds an icon matching the alert color;
+/// `icon_start` replaces it.
+///
 /// ### Add to `input.css`
 /// ```css
 /// @source inline("alert alert-outline alert-dash alert-soft alert-info alert-success alert-warning alert-error alert-vertical alert-horizontal");
@@ -28,6 +34,18 @@ pub fn Alert(
     #[prop(optional, into)]
     direction: Signal<AlertDirection>,
 
+    /// Icon shown before the content, replacing the color's default icon (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the content (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
+    /// Shows the default icon of the alert color (`icons` feature)
+    #[prop(optional, into)]
+    show_icon: Signal<bool>,
+
     /// Node reference for the alert element
     #[prop(optional)]
     node_ref: NodeRef<Div>,
@@ -53,7 +71,16 @@ pub fn Alert(
                 )
             }
         >
+            {icon_slot(
+                move || {
+                    icon_start
+                        .get()
+                        .or_else(|| show_icon.get().then(|| color.get().default_icon()).flatten())
+                },
+                || "1.5rem",
+            )}
             {children()}
+            {icon_slot(move || icon_end.get(), || "1.5rem")}
         </div>
     }
 }
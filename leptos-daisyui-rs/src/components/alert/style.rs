@@ -1,3 +1,5 @@
+use super::super::utils::IconSlot;
+
 /// # Alert Style Variants
 ///
 /// Style enum for daisyUI alert style classes that control the visual appearance
@@ -64,6 +66,24 @@ impl AlertColor {
             AlertColor::Error => "alert-error",
         }
     }
+
+    /// Icon shown by default for this color
+    #[cfg(feature = "icons")]
+    pub fn default_icon(&self) -> Option<IconSlot> {
+        match self {
+            AlertColor::Default => None,
+            AlertColor::Info => Some(icondata::AiInfoCircleOutlined),
+            AlertColor::Success => Some(icondata::AiCheckCircleOutlined),
+            AlertColor::Warning => Some(icondata::AiWarningOutlined),
+            AlertColor::Error => Some(icondata::AiCloseCircleOutlined),
+        }
+    }
+
+    /// Icon shown by default for this color (always `None` without the `icons` feature)
+    #[cfg(not(feature = "icons"))]
+    pub fn default_icon(&self) -> Option<IconSlot> {
+        None
+    }
 }
 
 /// # Alert Direction Variants
@@ -1,6 +1,15 @@
-use super::style::AvatarModifier;
+use super::{
+    super::{
+        mask::MaskType,
+        status::{Status, StatusColor},
+    },
+    style::{AvatarModifier, AvatarSize},
+};
 use crate::merge_classes;
-use leptos::{html::Div, prelude::*};
+use leptos::{
+    html::{Div, Img},
+    prelude::*,
+};
 
 /// # Avatar Component
 ///
@@ -59,8 +68,21 @@ pub fn Avatar(
 ///
 /// A reactive Leptos wrapper for daisyUI's avatar group component that displays
 /// multiple avatars in a stacked or overlapping layout.
+///
+/// With `max`, only the first `max` direct children are shown, followed by a `+N`
+/// placeholder avatar counting the hidden ones. Children are counted as written, so a
+/// `<For>` or a `move ||` closure counts as one child; use [`AvatarGroupList`] to limit
+/// a dynamic list.
 #[component]
 pub fn AvatarGroup(
+    /// Maximum number of avatars to show before the `+N` overflow avatar
+    #[prop(optional, into)]
+    max: Option<usize>,
+
+    /// Size of the overflow avatar
+    #[prop(optional, into)]
+    size: Signal<AvatarSize>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -70,11 +92,293 @@ pub fn AvatarGroup(
     node_ref: NodeRef<Div>,
 
     /// Avatar components to display in the group
-    children: Children,
+    children: ChildrenFragment,
 ) -> impl IntoView {
+    let mut avatars = children().nodes;
+    let (shown, hidden) = split_overflow(avatars.len(), max);
+    avatars.truncate(shown);
+
     view! {
         <div node_ref=node_ref class=move || merge_classes!("avatar-group", class)>
-            {children()}
+            {avatars}
+            {(hidden > 0).then(|| overflow_avatar(hidden, size))}
         </div>
     }
 }
+
+/// # Avatar Group List Component
+///
+/// An [`AvatarGroup`] rendered from a list of items, with one avatar per item. With
+/// `max`, only the first `max` items get an avatar, followed by a `+N` placeholder avatar
+/// that stays in sync as items are added or removed.
+///
+/// ## Node References
+/// - `node_ref` - References the avatar group `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn AvatarGroupList<T>(
+    /// Items to show avatars for
+    #[prop(into)]
+    items: Signal<Vec<T>>,
+
+    /// Unique key of an item
+    #[prop(into)]
+    key: Callback<T, String>,
+
+    /// Avatar of an item, e.g. an [`AvatarImage`]
+    #[prop(into)]
+    avatar: Callback<T, AnyView>,
+
+    /// Maximum number of avatars to show before the `+N` overflow avatar
+    #[prop(optional, into)]
+    max: MaybeProp<usize>,
+
+    /// Size of the overflow avatar
+    #[prop(optional, into)]
+    size: Signal<AvatarSize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the avatar group container
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let overflow = move || items.with(|items| split_overflow(items.len(), max.get()));
+    let shown_items = move || {
+        let (shown, _) = overflow();
+        items.with(|items| {
+            items[..shown]
+                .iter()
+                .map(|item| (key.run(item.clone()), item.clone()))
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("avatar-group", class)>
+            <For
+                each=shown_items
+                key=|(key, _)| key.clone()
+                children=move |(_, item)| avatar.run(item)
+            />
+            {move || {
+                let (_, hidden) = overflow();
+                (hidden > 0).then(|| overflow_avatar(hidden, size))
+            }}
+        </div>
+    }
+}
+
+/// Placeholder avatar counting the avatars hidden by a group's `max`
+fn overflow_avatar(hidden: usize, size: Signal<AvatarSize>) -> impl IntoView {
+    view! {
+        <div class="avatar avatar-placeholder">
+            <div class=move || {
+                merge_classes!(
+                    "bg-neutral text-neutral-content rounded-full",
+                    size.get().as_str(),
+                    size.get().text_class()
+                )
+            }>
+                <span>{format!("+{hidden}")}</span>
+            </div>
+        </div>
+    }
+}
+
+/// Number of avatars shown and hidden when a group of `len` avatars is limited to `max`
+fn split_overflow(len: usize, max: Option<usize>) -> (usize, usize) {
+    let shown = max.map_or(len, |max| max.min(len));
+    (shown, len - shown)
+}
+
+/// # Avatar Image Component
+///
+/// An avatar showing an image, falling back to the initials of `name` on a background
+/// color derived from the name when there is no `src` or the image fails to load.
+/// Optionally shows a [`Status`] presence dot and crops to a [`MaskType`] shape.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("avatar avatar-placeholder rounded-full mask w-8 w-10 w-12 w-16 w-24 text-xs text-sm text-base text-xl text-3xl absolute bottom-0 right-0 ring-2 ring-base-100 bg-primary text-primary-content bg-secondary text-secondary-content bg-accent text-accent-content bg-info text-info-content bg-success text-success-content bg-warning text-warning-content bg-error text-error-content bg-neutral text-neutral-content");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the avatar container `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn AvatarImage(
+    /// Name of the person, used for the alt text, initials and fallback color
+    #[prop(into)]
+    name: Signal<String>,
+
+    /// Image URL
+    #[prop(optional, into)]
+    src: MaybeProp<String>,
+
+    /// Size of the avatar
+    #[prop(optional, into)]
+    size: Signal<AvatarSize>,
+
+    /// Mask shape; the avatar is round when unset
+    #[prop(optional, into)]
+    mask: MaybeProp<MaskType>,
+
+    /// Color of the presence dot; no dot when unset
+    #[prop(optional, into)]
+    status: MaybeProp<StatusColor>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the avatar container
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let failed_src = RwSignal::new(None::<String>);
+    let image = move || {
+        src.get()
+            .filter(|src| failed_src.with(|failed| failed.as_ref() != Some(src)))
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "avatar",
+                    if image().is_none() { "avatar-placeholder" } else { "" },
+                    class
+                )
+            }
+        >
+            <div class=move || {
+                let (shape, mask_class) = match mask.get() {
+                    Some(mask) => ("mask", mask.as_str()),
+                    None => ("rounded-full", ""),
+                };
+                let fallback = if image().is_none() {
+                    name.with(|name| avatar_color(name))
+                } else {
+                    ""
+                };
+                merge_classes!(shape, mask_class, size.get().as_str(), fallback)
+            }>
+                {move || match image() {
+                    Some(src) => {
+                        // An image that failed before hydration never fires `error` again
+                        let img_ref = NodeRef::<Img>::new();
+                        let failed = src.clone();
+                        img_ref.on_load(move |img| {
+                            if img.complete() && img.natural_width() == 0 {
+                                failed_src.set(Some(failed));
+                            }
+                        });
+                        view! {
+                            <img
+                                node_ref=img_ref
+                                src=src.clone()
+                                alt=name
+                                on:error=move |_| failed_src.set(Some(src.clone()))
+                            />
+                        }
+                            .into_any()
+                    }
+                    None => {
+                        view! {
+                            <span
+                                class=move || size.get().text_class()
+                                role="img"
+                                aria-label=name
+                            >
+                                {move || name.with(|name| initials(name))}
+                            </span>
+                        }
+                            .into_any()
+                    }
+                }}
+            </div>
+            {move || {
+                status
+                    .get()
+                    .map(|color| {
+                        view! {
+                            <Status color=color class="absolute bottom-0 right-0 ring-2 ring-base-100" />
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Background and text color classes of generated avatars
+const AVATAR_COLORS: [&str; 8] = [
+    "bg-primary text-primary-content",
+    "bg-secondary text-secondary-content",
+    "bg-accent text-accent-content",
+    "bg-info text-info-content",
+    "bg-success text-success-content",
+    "bg-warning text-warning-content",
+    "bg-error text-error-content",
+    "bg-neutral text-neutral-content",
+];
+
+/// Initials of a name: the first letters of its first and last words, uppercased
+pub(crate) fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.next_back().and_then(|word| word.chars().next());
+
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Color classes derived from a name, stable across renders and sessions
+pub(crate) fn avatar_color(name: &str) -> &'static str {
+    // FNV-1a, so the color does not depend on the standard library's hasher
+    let hash = name.trim().bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    AVATAR_COLORS[hash as usize % AVATAR_COLORS.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_first_and_last_initials() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("  grace brewster murray hopper "), "GH");
+        assert_eq!(initials("ferris"), "F");
+        assert_eq!(initials("élodie durand"), "ÉD");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn derives_stable_colors() {
+        assert_eq!(avatar_color("Ada Lovelace"), avatar_color(" Ada Lovelace "));
+        assert!(AVATAR_COLORS.contains(&avatar_color("Ada Lovelace")));
+        let distinct = ["Ada", "Grace", "Linus", "Ken", "Barbara", "Margaret"]
+            .iter()
+            .map(|name| avatar_color(name))
+            .collect::<std::collections::HashSet<_>>();
+        assert!(distinct.len() > 1);
+    }
+
+    #[test]
+    fn splits_overflowing_avatars() {
+        assert_eq!(split_overflow(5, Some(3)), (3, 2));
+        assert_eq!(split_overflow(3, Some(3)), (3, 0));
+        assert_eq!(split_overflow(2, Some(3)), (2, 0));
+        assert_eq!(split_overflow(4, Some(0)), (0, 4));
+        assert_eq!(split_overflow(4, None), (4, 0));
+    }
+}
@@ -29,3 +29,50 @@ impl AvatarModifier {
         }
     }
 }
+
+/// # Avatar Size Variants
+///
+/// Size enum for generated avatars like [`AvatarImage`](super::AvatarImage) and the
+/// overflow avatar of [`AvatarGroup`](super::AvatarGroup).
+#[derive(Clone, Debug, Default)]
+pub enum AvatarSize {
+    /// Extra small size (2rem)
+    Xs,
+
+    /// Small size (2.5rem)
+    Sm,
+
+    /// Medium size (3rem, default)
+    #[default]
+    Md,
+
+    /// Large size (4rem)
+    Lg,
+
+    /// Extra large size (6rem)
+    Xl,
+}
+
+impl AvatarSize {
+    /// CSS class string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AvatarSize::Xs => "w-8",
+            AvatarSize::Sm => "w-10",
+            AvatarSize::Md => "w-12",
+            AvatarSize::Lg => "w-16",
+            AvatarSize::Xl => "w-24",
+        }
+    }
+
+    /// Text size class for initials
+    pub fn text_class(&self) -> &'static str {
+        match self {
+            AvatarSize::Xs => "text-xs",
+            AvatarSize::Sm => "text-sm",
+            AvatarSize::Md => "text-base",
+            AvatarSize::Lg => "text-xl",
+            AvatarSize::Xl => "text-3xl",
+        }
+    }
+}
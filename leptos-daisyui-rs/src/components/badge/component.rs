@@ -1,4 +1,7 @@
-use super::style::{BadgeColor, BadgeSize, BadgeStyle};
+use super::{
+    super::utils::{IconSlot, icon_slot},
+    style::{BadgeColor, BadgeSize, BadgeStyle},
+};
 use crate::merge_classes;
 use leptos::{html::Div, prelude::*};
 
@@ -28,6 +31,14 @@ pub fn Badge(
     #[prop(optional, into)]
     size: Signal<BadgeSize>,
 
+    /// Icon shown before the content (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the content (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -53,7 +64,9 @@ pub fn Badge(
                 )
             }
         >
+            {icon_slot(move || icon_start.get(), move || size.get().icon_size())}
             {children()}
+            {icon_slot(move || icon_end.get(), move || size.get().icon_size())}
         </div>
     }
 }
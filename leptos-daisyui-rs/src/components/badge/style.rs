@@ -121,4 +121,15 @@ impl BadgeSize {
             BadgeSize::Xl => "badge-xl",
         }
     }
+
+    /// CSS width and height of icons in slots of this size
+    pub fn icon_size(&self) -> &'static str {
+        match self {
+            BadgeSize::Xs => "0.625rem",
+            BadgeSize::Sm => "0.75rem",
+            BadgeSize::Md => "0.875rem",
+            BadgeSize::Lg => "1rem",
+            BadgeSize::Xl => "1.25rem",
+        }
+    }
 }
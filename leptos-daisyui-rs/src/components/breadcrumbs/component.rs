@@ -1,6 +1,20 @@
+#[cfg(feature = "router")]
+use super::super::dropdown::{DropdownContent, DropdownDetails, DropdownSummary};
 use crate::merge_classes;
 use leptos::html::{Div, Li, Ul};
 use leptos::prelude::*;
+#[cfg(feature = "router")]
+use leptos::task::spawn_local;
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
+#[cfg(feature = "router")]
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    ops::Range,
+    pin::Pin,
+    sync::Arc,
+};
 
 /// # Breadcrumbs Component
 ///
@@ -46,6 +60,9 @@ pub fn Breadcrumbs(
 
 /// # BreadcrumbItem Component
 ///
+/// With the `router` feature enabled, an item with an `href` links through `leptos_router`
+/// and has to be rendered inside a `<Router>`.
+///
 /// ## Node References
 /// - `node_ref` - References the top `<li>` element ([HTMLLiElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLiElement))
 #[component]
@@ -69,10 +86,275 @@ pub fn BreadcrumbItem(
     view! {
         <li node_ref=node_ref class=class>
             {if let Some(href) = href.get() {
-                view! { <a href=href>{children.map(|v| v())}</a> }.into_any()
+                #[cfg(feature = "router")]
+                let link = view! { <RouterLink href=href>{children.map(|v| v())}</RouterLink> };
+                #[cfg(not(feature = "router"))]
+                let link = view! { <a href=href>{children.map(|v| v())}</a> };
+
+                link.into_any()
             } else {
                 view! { {children.map(|v| v())} }.into_any()
             }}
         </li>
     }
 }
+
+/// # AutoBreadcrumbs Component
+///
+/// Breadcrumbs derived from the current `leptos_router` location, one crumb per path
+/// segment. Labels default to the prettified segment and can be replaced through `label`,
+/// which may resolve asynchronously (e.g. fetching a name for an id). When there are more
+/// than `max_items` crumbs, the middle ones collapse into an ellipsis dropdown. The last
+/// crumb is the current page and is not a link.
+///
+/// Only available with the `router` feature, and must be rendered inside a `<Router>`.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("breadcrumbs dropdown dropdown-content menu bg-base-100 rounded-box z-1 w-52 p-2 shadow-sm cursor-pointer");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the top `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[cfg(feature = "router")]
+#[component]
+pub fn AutoBreadcrumbs(
+    /// Label of a leading crumb linking to `/`; omitted when unset
+    #[prop(optional, into)]
+    home: MaybeProp<String>,
+
+    /// Resolves the label of a crumb, synchronously or asynchronously
+    #[prop(optional, into)]
+    label: Option<BreadcrumbLabel>,
+
+    /// Maximum number of visible crumbs before the middle ones collapse (at least 2)
+    #[prop(optional)]
+    max_items: Option<usize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the top `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let location = leptos_router::hooks::use_location();
+
+    let crumbs = Memo::new(move |_| {
+        let mut crumbs = location.pathname.with(|path| path_segments(path));
+        if let Some(home) = home.get() {
+            crumbs.insert(0, BreadcrumbSegment::home(home));
+        }
+        crumbs
+    });
+
+    let labels = RwSignal::new(HashMap::<String, String>::new());
+
+    if let Some(label) = label {
+        let mut requested = HashSet::new();
+
+        Effect::new(move || {
+            for crumb in crumbs.get() {
+                if !requested.insert(crumb.href.clone()) {
+                    continue;
+                }
+
+                let resolved = (label.0)(crumb.clone());
+                spawn_local(async move {
+                    if let Some(text) = resolved.await {
+                        labels.try_update(|labels| {
+                            labels.insert(crumb.href, text);
+                        });
+                    }
+                });
+            }
+        });
+    }
+
+    let crumb_label = move |crumb: &BreadcrumbSegment| {
+        let BreadcrumbSegment { href, label, .. } = crumb.clone();
+        move || {
+            labels
+                .with(|labels| labels.get(&href).cloned())
+                .unwrap_or_else(|| label.clone())
+        }
+    };
+
+    let crumb_link = move |crumb: BreadcrumbSegment| {
+        let text = crumb_label(&crumb);
+        view! { <BreadcrumbItem href=crumb.href>{text}</BreadcrumbItem> }
+    };
+
+    view! {
+        <Breadcrumbs outer_class=class outer_node_ref=node_ref>
+            {move || {
+                let mut crumbs = crumbs.get();
+                let Some(current) = crumbs.pop() else {
+                    return ().into_any();
+                };
+                let current = crumb_label(&current);
+
+                let hidden = collapsed_range(crumbs.len() + 1, max_items.unwrap_or(usize::MAX))
+                    .map(|range| crumbs.splice(range, []).collect::<Vec<_>>())
+                    .unwrap_or_default();
+                let visible = crumbs.into_iter().map(crumb_link).collect::<Vec<_>>();
+                let mut visible = visible.into_iter();
+
+                view! {
+                    {visible.next()}
+                    {(!hidden.is_empty())
+                        .then(|| {
+                            view! {
+                                <BreadcrumbItem>
+                                    <DropdownDetails>
+                                        <DropdownSummary class="cursor-pointer">
+                                            "…"
+                                        </DropdownSummary>
+                                        <DropdownContent
+                                            is_menu=true
+                                            class="bg-base-100 rounded-box z-1 w-52 p-2 shadow-sm"
+                                        >
+                                            {hidden.into_iter().map(crumb_link).collect_view()}
+                                        </DropdownContent>
+                                    </DropdownDetails>
+                                </BreadcrumbItem>
+                            }
+                        })}
+                    {visible.collect_view()}
+                    <BreadcrumbItem>
+                        <span aria-current="page">{current}</span>
+                    </BreadcrumbItem>
+                }
+                    .into_any()
+            }}
+        </Breadcrumbs>
+    }
+}
+
+#[cfg(feature = "router")]
+type BreadcrumbLabelFn =
+    Arc<dyn Fn(BreadcrumbSegment) -> Pin<Box<dyn Future<Output = Option<String>>>> + Send + Sync>;
+
+/// Label resolver for [`AutoBreadcrumbs`]
+///
+/// Returning `None` keeps the default label. Any `Fn(&BreadcrumbSegment) -> Option<String>`
+/// converts into a synchronous resolver.
+#[cfg(feature = "router")]
+#[derive(Clone)]
+pub struct BreadcrumbLabel(BreadcrumbLabelFn);
+
+#[cfg(feature = "router")]
+impl BreadcrumbLabel {
+    /// Creates a resolver that returns the label immediately
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: Fn(&BreadcrumbSegment) -> Option<String> + Send + Sync + 'static,
+    {
+        Self(Arc::new(move |crumb| {
+            let label = resolve(&crumb);
+            Box::pin(async move { label })
+        }))
+    }
+
+    /// Creates a resolver whose label arrives later, e.g. from a server call
+    pub fn new_async<F, Fut>(resolve: F) -> Self
+    where
+        F: Fn(BreadcrumbSegment) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<String>> + 'static,
+    {
+        Self(Arc::new(move |crumb| Box::pin(resolve(crumb))))
+    }
+}
+
+#[cfg(feature = "router")]
+impl<F> From<F> for BreadcrumbLabel
+where
+    F: Fn(&BreadcrumbSegment) -> Option<String> + Send + Sync + 'static,
+{
+    fn from(resolve: F) -> Self {
+        Self::new(resolve)
+    }
+}
+
+/// A crumb derived from one path segment
+#[cfg(feature = "router")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbSegment {
+    /// The raw path segment, empty for the home crumb
+    pub segment: String,
+
+    /// The path up to and including this segment
+    pub href: String,
+
+    /// The default label
+    pub label: String,
+}
+
+#[cfg(feature = "router")]
+impl BreadcrumbSegment {
+    fn home(label: String) -> Self {
+        Self {
+            segment: String::new(),
+            href: "/".to_string(),
+            label,
+        }
+    }
+}
+
+/// Splits a location path into crumbs, ignoring empty segments
+#[cfg(feature = "router")]
+pub(crate) fn path_segments(path: &str) -> Vec<BreadcrumbSegment> {
+    let mut href = String::new();
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            href.push('/');
+            href.push_str(segment);
+            BreadcrumbSegment {
+                segment: segment.to_string(),
+                href: href.clone(),
+                label: segment_label(segment),
+            }
+        })
+        .collect()
+}
+
+/// Turns a path segment such as `user-settings` into `User settings`
+#[cfg(feature = "router")]
+pub(crate) fn segment_label(segment: &str) -> String {
+    let text = segment.replace(['-', '_'], " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+/// The range of crumbs hidden behind the ellipsis, keeping the first and the last ones
+#[cfg(feature = "router")]
+pub(crate) fn collapsed_range(len: usize, max_items: usize) -> Option<Range<usize>> {
+    let max_items = max_items.max(2);
+    (len > max_items).then(|| 1..len - (max_items - 1))
+}
+
+#[cfg(all(test, feature = "router"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_segments_builds_cumulative_hrefs() {
+        let crumbs = path_segments("/users/42/edit-profile/");
+        let hrefs: Vec<_> = crumbs.iter().map(|c| c.href.as_str()).collect();
+        assert_eq!(hrefs, ["/users", "/users/42", "/users/42/edit-profile"]);
+        assert_eq!(crumbs[2].label, "Edit profile");
+        assert!(path_segments("/").is_empty());
+    }
+
+    #[test]
+    fn collapsed_range_keeps_ends() {
+        assert_eq!(collapsed_range(4, 4), None);
+        assert_eq!(collapsed_range(6, 4), Some(1..3));
+        assert_eq!(collapsed_range(5, 0), Some(1..4));
+    }
+}
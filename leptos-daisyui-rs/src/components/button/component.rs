@@ -1,9 +1,14 @@
-use super::style::{ButtonColor, ButtonShape, ButtonSize, ButtonStyle};
+use super::{
+    super::utils::{IconSlot, icon_slot},
+    style::{ButtonColor, ButtonShape, ButtonSize, ButtonStyle},
+};
 use crate::merge_classes;
 use leptos::{
     html::{A, Button as HTMLButton},
     prelude::*,
 };
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
 
 /// # Button Component
 ///
@@ -47,6 +52,14 @@ pub fn Button(
     #[prop(optional, into)]
     disabled: Signal<bool>,
 
+    /// Icon shown before the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Node reference for the button element
     #[prop(optional, into)]
     node_ref: NodeRef<HTMLButton>,
@@ -77,7 +90,9 @@ pub fn Button(
             class:btn-disabled=disabled
             class:loading=loading
         >
+            {icon_slot(move || icon_start.get(), move || size.get().icon_size())}
             {children()}
+            {icon_slot(move || icon_end.get(), move || size.get().icon_size())}
         </button>
     }
 }
@@ -87,6 +102,10 @@ pub fn Button(
 /// An anchor element styled as a daisyUI button for navigation actions.
 /// Provides the same styling options as Button but renders as a link.
 ///
+/// With the `router` feature enabled, the button renders `leptos_router`'s `<A>` so
+/// internal URLs navigate without a full page load. A button with an `href` then needs
+/// to be rendered inside a `<Router>`.
+///
 /// ## Node References
 /// - `node_ref` - References the `<a>` element ([HTMLAnchorElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement))
 #[component]
@@ -122,6 +141,30 @@ pub fn LinkButton(
     /// Link content (text, icons, or other elements)
     children: Children,
 ) -> impl IntoView {
+    #[cfg(feature = "router")]
+    if !href.is_empty() {
+        return view! {
+            <RouterLink
+                href=href
+                {..}
+                node_ref=node_ref
+                class=move || {
+                    merge_classes!(
+                        "btn",
+                        color.get().as_str(),
+                        style.get().as_str(),
+                        size.get().as_str(),
+                        shape.get().as_str(),
+                        class
+                    )
+                }
+            >
+                {children()}
+            </RouterLink>
+        }
+        .into_any();
+    }
+
     view! {
         <a
             href=href
@@ -141,4 +184,5 @@ pub fn LinkButton(
             {children()}
         </a>
     }
+    .into_any()
 }
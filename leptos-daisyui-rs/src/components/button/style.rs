@@ -126,6 +126,17 @@ impl ButtonSize {
             ButtonSize::Xl => "btn-xl",
         }
     }
+
+    /// CSS width and height of icons in slots of this size
+    pub fn icon_size(&self) -> &'static str {
+        match self {
+            ButtonSize::Xs => "0.75rem",
+            ButtonSize::Sm => "1rem",
+            ButtonSize::Md => "1.25rem",
+            ButtonSize::Lg => "1.5rem",
+            ButtonSize::Xl => "1.75rem",
+        }
+    }
 }
 
 /// # Button Shape Variants
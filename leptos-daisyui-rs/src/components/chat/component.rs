@@ -1,4 +1,7 @@
-use super::style::{ChatBubbleColor, ChatPlacement};
+use super::{
+    super::loading::{Loading, LoadingSize, LoadingType},
+    style::{ChatBubbleColor, ChatPlacement},
+};
 use crate::merge_classes;
 use leptos::html::Div;
 use leptos::prelude::*;
@@ -166,3 +169,296 @@ pub fn ChatFooter(
         </div>
     }
 }
+
+/// Distance in pixels from the bottom within which a [`ChatThread`] keeps following new messages
+const STICK_THRESHOLD: i32 = 32;
+
+/// # Chat Thread Component
+///
+/// Renders a whole conversation from a list of messages. Messages are placed at the end
+/// when sent by `current_sender` and at the start otherwise. Consecutive messages from
+/// one sender are grouped: the avatar and header are shown on the first message of a
+/// group and the footer on the last.
+///
+/// The thread is a scroll container that sticks to the bottom when new messages arrive,
+/// unless the user has scrolled up. In that case a pill shows how many messages are unread
+/// and jumps back to the bottom when clicked.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("chat chat-start chat-end chat-image chat-header chat-bubble chat-footer loading loading-dots loading-sm btn btn-sm btn-primary overflow-y-auto sticky bottom-2 flex justify-center pointer-events-none pointer-events-auto");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the scrolling `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn ChatThread<M>(
+    /// Messages in chronological order
+    #[prop(into)]
+    messages: Signal<Vec<M>>,
+
+    /// Unique key of a message
+    #[prop(into)]
+    key: Callback<M, String>,
+
+    /// Sender of a message, used for placement and grouping
+    #[prop(into)]
+    sender: Callback<M, String>,
+
+    /// Bubble content of a message
+    #[prop(into)]
+    content: Callback<M, AnyView>,
+
+    /// Sender whose messages are placed at the end
+    #[prop(optional, into)]
+    current_sender: MaybeProp<String>,
+
+    /// Avatar shown on the first message of a group
+    #[prop(optional, into)]
+    avatar: Option<Callback<M, AnyView>>,
+
+    /// Header shown on the first message of a group; defaults to the sender
+    #[prop(optional, into)]
+    header: Option<Callback<M, AnyView>>,
+
+    /// Footer shown on the last message of a group
+    #[prop(optional, into)]
+    footer: Option<Callback<M, AnyView>>,
+
+    /// Bubble color of a message
+    #[prop(optional, into)]
+    color: Option<Callback<M, ChatBubbleColor>>,
+
+    /// Whether to show the typing indicator
+    #[prop(optional, into)]
+    typing: Signal<bool>,
+
+    /// Header of the typing indicator, e.g. "Alice is typing"
+    #[prop(optional, into)]
+    typing_label: MaybeProp<String>,
+
+    /// Additional CSS classes to apply to the thread container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the thread container element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView
+where
+    M: Clone + Send + Sync + 'static,
+{
+    let at_bottom = RwSignal::new(true);
+    let unread = RwSignal::new(0usize);
+
+    let scroll_to_bottom = move || {
+        if let Some(thread) = node_ref.get_untracked() {
+            thread.set_scroll_top(thread.scroll_height());
+        }
+        unread.set(0);
+    };
+
+    let on_scroll = move |_| {
+        let Some(thread) = node_ref.get_untracked() else {
+            return;
+        };
+        let bottom = thread.scroll_height() - thread.scroll_top() - thread.client_height()
+            <= STICK_THRESHOLD;
+        if at_bottom.get_untracked() != bottom {
+            at_bottom.set(bottom);
+        }
+        if bottom && unread.get_untracked() > 0 {
+            unread.set(0);
+        }
+    };
+
+    Effect::new(move |prev: Option<usize>| {
+        let len = messages.with(Vec::len);
+        typing.track();
+
+        if prev.is_none() || at_bottom.get_untracked() {
+            request_animation_frame(scroll_to_bottom);
+        } else if let Some(prev) = prev.filter(|prev| len > *prev) {
+            unread.update(|unread| *unread += len - prev);
+        }
+
+        len
+    });
+
+    let rows = move || {
+        messages.with(|messages| {
+            let senders = messages
+                .iter()
+                .map(|message| sender.run(message.clone()))
+                .collect::<Vec<_>>();
+            let positions = group_positions(&senders);
+
+            messages
+                .iter()
+                .cloned()
+                .zip(senders)
+                .zip(positions)
+                .map(|((message, from), position)| {
+                    (key.run(message.clone()), position, from, message)
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let row_view = move |(_, position, from, message): (String, ChatGroupPosition, String, M)| {
+        let is_own = {
+            let from = from.clone();
+            move || current_sender.with(|current| current.as_deref() == Some(from.as_str()))
+        };
+        let placement = Signal::derive(move || {
+            if is_own() {
+                ChatPlacement::End
+            } else {
+                ChatPlacement::Start
+            }
+        });
+        let bubble_color = color
+            .map(|color| color.run(message.clone()))
+            .unwrap_or_default();
+
+        let image = avatar.filter(|_| position.first).map(|avatar| {
+            view! { <ChatImage>{avatar.run(message.clone())}</ChatImage> }
+        });
+        let heading = position.first.then(|| {
+            let heading = match header {
+                Some(header) => header.run(message.clone()),
+                None => from.into_any(),
+            };
+            view! { <ChatHeader>{heading}</ChatHeader> }
+        });
+        let foot = footer.filter(|_| position.last).map(|footer| {
+            view! { <ChatFooter class="opacity-50">{footer.run(message.clone())}</ChatFooter> }
+        });
+
+        view! {
+            <Chat placement=placement>
+                {image}
+                {heading}
+                <ChatBubble color=bubble_color>{content.run(message)}</ChatBubble>
+                {foot}
+            </Chat>
+        }
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            class=move || merge_classes!("overflow-y-auto", class)
+            role="log"
+            aria-live="polite"
+            on:scroll=on_scroll
+        >
+            <For
+                each=rows
+                key=|(key, position, ..)| (key.clone(), *position)
+                children=row_view
+            />
+            {move || {
+                typing
+                    .get()
+                    .then(|| {
+                        view! {
+                            <Chat placement=ChatPlacement::Start>
+                                {move || {
+                                    typing_label
+                                        .get()
+                                        .map(|label| view! { <ChatHeader>{label}</ChatHeader> })
+                                }}
+                                <ChatBubble>
+                                    <Loading loading_type=LoadingType::Dots size=LoadingSize::Sm />
+                                </ChatBubble>
+                            </Chat>
+                        }
+                    })
+            }}
+            {move || {
+                let count = unread.get();
+                (count > 0)
+                    .then(|| {
+                        view! {
+                            <div class="sticky bottom-2 flex justify-center pointer-events-none">
+                                <button
+                                    type="button"
+                                    class="btn btn-sm btn-primary pointer-events-auto"
+                                    on:click=move |_| scroll_to_bottom()
+                                >
+                                    {new_messages_label(count)}
+                                </button>
+                            </div>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Position of a message within a run of consecutive messages from one sender
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct ChatGroupPosition {
+    /// Whether the message starts a group
+    pub first: bool,
+
+    /// Whether the message ends a group
+    pub last: bool,
+}
+
+/// Groups consecutive equal senders, returning the position of each message in its group
+pub(crate) fn group_positions<S: PartialEq>(senders: &[S]) -> Vec<ChatGroupPosition> {
+    senders
+        .iter()
+        .enumerate()
+        .map(|(index, current)| ChatGroupPosition {
+            first: index == 0 || senders[index - 1] != *current,
+            last: senders.get(index + 1) != Some(current),
+        })
+        .collect()
+}
+
+/// Label of the unread messages pill
+fn new_messages_label(count: usize) -> String {
+    match count {
+        1 => "1 new message".to_string(),
+        count => format!("{count} new messages"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(first: bool, last: bool) -> ChatGroupPosition {
+        ChatGroupPosition { first, last }
+    }
+
+    #[test]
+    fn groups_consecutive_senders() {
+        assert_eq!(
+            group_positions(&["a", "a", "b", "a", "a", "a"]),
+            vec![
+                position(true, false),
+                position(false, true),
+                position(true, true),
+                position(true, false),
+                position(false, false),
+                position(false, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_empty_and_single() {
+        assert!(group_positions::<&str>(&[]).is_empty());
+        assert_eq!(group_positions(&["a"]), vec![position(true, true)]);
+    }
+
+    #[test]
+    fn pluralizes_new_messages_label() {
+        assert_eq!(new_messages_label(1), "1 new message");
+        assert_eq!(new_messages_label(3), "3 new messages");
+    }
+}
@@ -1,8 +1,11 @@
 use super::style::{CollapseForceModifier, CollapseModifier};
 use crate::merge_classes;
 use leptos::{
-    html::{Div, Input},
+    ev,
+    html::{Details, Div, Input, Summary},
     prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{HtmlDetailsElement, Node},
 };
 
 /// A collapsible container that can expand and contract to show or hide content.
@@ -10,6 +13,10 @@ use leptos::{
 /// The `Collapse` component uses tabindex-based interaction, allowing users to click
 /// on the collapse to toggle its state. For more control, use `CollapseCheckbox`.
 ///
+/// Bind `open` to read and set the state from code; `on_toggle` is called when the user
+/// opens or closes the collapse, not when the state is set from code. With `focus_open`,
+/// the state follows focus entering and leaving the collapse.
+///
 /// /// ### Add to `input.css`
 /// ```css
 /// @source inline("collapse collapse-title collapse-content collapse-arrow collapse-plus collapse-open collapse-close");
@@ -28,6 +35,14 @@ pub fn Collapse(
     #[prop(optional, into)]
     checked: Signal<bool>,
 
+    /// Two-way bound open state; takes precedence over `checked`
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+
+    /// Called with the new state when the user opens or closes the collapse
+    #[prop(optional, into)]
+    on_toggle: Option<Callback<bool>>,
+
     /// Visual style and behavior modifier for the collapse
     #[prop(optional, into)]
     modifier: Signal<CollapseModifier>,
@@ -52,10 +67,50 @@ pub fn Collapse(
     /// Child elements, typically CollapseTitle and CollapseContent
     children: Children,
 ) -> impl IntoView {
+    let open = open.unwrap_or_else(|| {
+        // seeded for server rendering, where effects don't run
+        let open = RwSignal::new(checked.get_untracked());
+        Effect::new(move || open.set(checked.get()));
+        open
+    });
+    provide_context(CollapseManager { open });
+
+    let set_open = move |value: bool| {
+        if open.get_untracked() == value {
+            return;
+        }
+
+        open.set(value);
+        if let Some(on_toggle) = on_toggle {
+            on_toggle.run(value);
+        }
+    };
+
+    let on_focusout = move |ev: ev::FocusEvent| {
+        if !focus_open.get_untracked() {
+            return;
+        }
+
+        let focus_stays = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+            .zip(outer_node_ref.get_untracked())
+            .is_some_and(|(target, outer)| outer.contains(Some(&target)));
+        if !focus_stays {
+            set_open(false);
+        }
+    };
+
     view! {
         <div
             node_ref=outer_node_ref
             tabindex=move || { if focus_open.get() { Some("0") } else { None } }
+            on:focusin=move |_| {
+                if focus_open.get_untracked() {
+                    set_open(true);
+                }
+            }
+            on:focusout=on_focusout
             class=move || {
                 merge_classes!(
                     "collapse",
@@ -69,7 +124,15 @@ pub fn Collapse(
                 if focus_open.get() {
                     ().into_any()
                 } else {
-                    view! { <input node_ref=inner_node_ref type="checkbox" checked=checked /> }
+                    view! {
+                        <input
+                            node_ref=inner_node_ref
+                            type="checkbox"
+                            checked=move || open.get()
+                            prop:checked=move || open.get()
+                            on:change=move |ev| set_open(event_target_checked(&ev))
+                        />
+                    }
                         .into_any()
                 }
             }}
@@ -79,6 +142,104 @@ pub fn Collapse(
     }
 }
 
+/// # Collapse Details Component
+///
+/// A collapse built on the native `<details>` element, so it opens and closes without
+/// JavaScript (e.g. before hydration). Use [`CollapseSummary`] as its title.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("collapse collapse-title collapse-content collapse-arrow collapse-plus collapse-open collapse-close");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the `<details>` element ([HTMLDetailsElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDetailsElement))
+#[component]
+pub fn CollapseDetails(
+    /// Two-way bound open state
+    #[prop(optional)]
+    open: RwSignal<bool>,
+
+    /// Called with the new state when the user opens or closes the collapse
+    #[prop(optional, into)]
+    on_toggle: Option<Callback<bool>>,
+
+    /// Visual style and behavior modifier for the collapse
+    #[prop(optional, into)]
+    modifier: Signal<CollapseModifier>,
+
+    /// Reactive signal controlling whether the collapse is open/close
+    #[prop(optional, into)]
+    force: Signal<CollapseForceModifier>,
+
+    /// Additional CSS classes to apply to the collapse container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the `<details>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Details>,
+
+    /// Child elements, typically CollapseSummary and CollapseContent
+    children: Children,
+) -> impl IntoView {
+    provide_context(CollapseManager { open });
+
+    let on_details_toggle = move |ev: ev::Event| {
+        let value = event_target::<HtmlDetailsElement>(&ev).open();
+        if open.get_untracked() == value {
+            return;
+        }
+
+        open.set(value);
+        if let Some(on_toggle) = on_toggle {
+            on_toggle.run(value);
+        }
+    };
+
+    view! {
+        <details
+            node_ref=node_ref
+            open=move || open.get()
+            class=move || {
+                merge_classes!(
+                    "collapse",
+                    modifier.get().as_str(),
+                    force.get().as_str(),
+                    class
+                )
+            }
+            on:toggle=on_details_toggle
+        >
+            {children()}
+        </details>
+    }
+}
+
+/// The clickable title of a [`CollapseDetails`], rendered as `<summary>`.
+///
+/// ## Node References
+/// - `node_ref` - References the `<summary>` element ([summary](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/summary))
+#[component]
+pub fn CollapseSummary(
+    /// Additional CSS classes to apply to the title element
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the `<summary>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Summary>,
+
+    /// Title content (text, icons, etc.)
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <summary node_ref=node_ref class=move || merge_classes!("collapse-title", class)>
+            {children()}
+        </summary>
+    }
+}
+
 /// The clickable title section of a collapse component.
 ///
 /// This component renders the header/title area that users click to toggle
@@ -109,7 +270,7 @@ pub fn CollapseTitle(
 /// The collapsible content section of a collapse component.
 ///
 /// This component renders the content that is shown/hidden when the collapse
-/// is toggled.
+/// is toggled. Use [`CollapseLazyContent`] to mount the content only while it is needed.
 ///
 /// ## Node References
 /// - `node_ref` - References the top `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
@@ -132,3 +293,57 @@ pub fn CollapseContent(
         </div>
     }
 }
+
+/// Lazily mounted content section of a collapse component.
+///
+/// Like [`CollapseContent`], but the content is only mounted once the surrounding
+/// [`Collapse`] or [`CollapseDetails`] first opens; with `unmount_on_close`, it is also
+/// removed again whenever the collapse closes. Outside of a collapse the content is
+/// always mounted.
+///
+/// ## Node References
+/// - `node_ref` - References the top `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn CollapseLazyContent(
+    /// Additional CSS classes to apply to the content element
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the content `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+
+    /// Unmounts the content whenever the collapse closes
+    #[prop(optional)]
+    unmount_on_close: bool,
+
+    /// Content to mount when the collapse opens
+    children: ChildrenFn,
+) -> impl IntoView {
+    let manager = use_context::<CollapseManager>();
+
+    let mounted = Memo::new(move |mounted: Option<&bool>| {
+        let Some(CollapseManager { open }) = manager else {
+            return true;
+        };
+
+        if unmount_on_close {
+            open.get()
+        } else {
+            mounted.copied().unwrap_or_default() || open.get()
+        }
+    });
+
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("collapse-content", class)>
+            {move || if mounted.get() { Some(children()) } else { None }}
+        </div>
+    }
+}
+
+/// Internal context manager for collapse open state.
+#[derive(Clone, Copy)]
+pub(crate) struct CollapseManager {
+    /// Signal tracking whether the collapse is open
+    open: RwSignal<bool>,
+}
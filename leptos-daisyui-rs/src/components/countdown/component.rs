@@ -1,5 +1,10 @@
 use crate::merge_classes;
-use leptos::{html::Span, prelude::*};
+use leptos::{
+    html::{Div, Span},
+    prelude::*,
+    web_sys::js_sys,
+};
+use std::time::Duration;
 
 /// # Countdown Component
 ///
@@ -72,3 +77,257 @@ pub fn CountdownValue(
         </span>
     }
 }
+
+/// # Countdown Timer Component
+///
+/// A live timer built on [`Countdown`] and [`CountdownValue`] that counts down to a
+/// target instant or for a fixed duration. Day and hour segments are only rendered
+/// when the countdown is long enough to need them. More than 99 days are shown as plain
+/// text, since daisyUI only animates values up to 99.
+///
+/// Use a [`CountdownController`] to pause, resume or reset the timer from outside.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("countdown grid grid-flow-col auto-cols-max gap-5 text-center flex flex-col font-mono");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the top `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn CountdownTimer(
+    /// Length of the countdown, ignored when `target` is set
+    #[prop(optional, into)]
+    duration: Signal<Duration>,
+
+    /// Instant to count down to, in milliseconds since the Unix epoch
+    #[prop(optional, into)]
+    target: Signal<Option<f64>>,
+
+    /// Render each segment in its own column with a unit label below it
+    #[prop(optional, into)]
+    labels: Signal<bool>,
+
+    /// Controller used to pause, resume and reset the timer
+    #[prop(optional)]
+    controller: CountdownController,
+
+    /// Called once when the countdown reaches zero
+    #[prop(optional, into)]
+    on_finish: Option<Callback<()>>,
+
+    /// Additional CSS classes to apply to the timer container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the timer `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let CountdownController {
+        paused,
+        remaining,
+        generation,
+    } = controller;
+
+    let total = RwSignal::new(Duration::ZERO);
+    let deadline = StoredValue::new(None::<f64>);
+    let finished = StoredValue::new(false);
+
+    // (Re)start whenever the length changes or `reset` is requested
+    Effect::new(move || {
+        generation.track();
+        let length = match target.get() {
+            Some(target) => millis_to_duration(target - now()),
+            None => duration.get(),
+        };
+
+        total.set(length);
+        remaining.set(length);
+        finished.set_value(false);
+        deadline.set_value((!paused.get_untracked()).then(|| deadline_after(length)));
+    });
+
+    Effect::new(move || {
+        if paused.get() {
+            deadline.set_value(None);
+        } else if deadline.get_value().is_none() && !finished.get_value() {
+            deadline.set_value(Some(deadline_after(remaining.get_untracked())));
+        }
+    });
+
+    let tick = move || {
+        let Some(end) = deadline.get_value() else {
+            return;
+        };
+
+        let left = millis_to_duration(end - now());
+        remaining.set(left);
+
+        if left.is_zero() && !finished.get_value() {
+            finished.set_value(true);
+            deadline.set_value(None);
+            if let Some(on_finish) = on_finish {
+                on_finish.run(());
+            }
+        }
+    };
+
+    if let Ok(handle) = set_interval_with_handle(tick, Duration::from_millis(250)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    // Round up so the display only reaches zero when the countdown has finished
+    let seconds = Memo::new(move |_| remaining.get().as_millis().div_ceil(1000) as u64);
+    let days = Memo::new(move |_| seconds.get() / 86_400);
+    let hours = Signal::derive(move || (seconds.get() / 3_600 % 24) as u8);
+    let minutes = Signal::derive(move || (seconds.get() / 60 % 60) as u8);
+    let secs = Signal::derive(move || (seconds.get() % 60) as u8);
+
+    let show_days = Signal::derive(move || total.get().as_secs() >= 86_400);
+    let show_hours = Signal::derive(move || total.get().as_secs() >= 3_600);
+
+    let wide_days = Memo::new(move |_| days.get() > 99);
+    let days_value = move || {
+        if wide_days.get() {
+            view! { <span class="font-mono">{move || days.get()}</span> }.into_any()
+        } else {
+            view! {
+                <Countdown class="font-mono">
+                    <CountdownValue value=Signal::derive(move || days.get() as u8) />
+                </Countdown>
+            }
+            .into_any()
+        }
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            role="timer"
+            class=move || {
+                let layout = if labels.get() {
+                    "grid grid-flow-col auto-cols-max gap-5 text-center"
+                } else {
+                    ""
+                };
+                merge_classes!(layout, class)
+            }
+        >
+            {move || {
+                if labels.get() {
+                    view! {
+                        <Show when=move || show_days.get()>
+                            <div class="flex flex-col">{days_value} "days"</div>
+                        </Show>
+                        <Show when=move || show_hours.get()>
+                            <CountdownSegment value=hours label="hours" />
+                        </Show>
+                        <CountdownSegment value=minutes label="min" />
+                        <CountdownSegment value=secs label="sec" />
+                    }
+                        .into_any()
+                } else {
+                    view! {
+                        <Show when=move || show_days.get()>
+                            {days_value}
+                            <span class="font-mono">":"</span>
+                        </Show>
+                        <Countdown class="font-mono">
+                            <Show when=move || show_hours.get()>
+                                <CountdownValue value=hours />
+                                ":"
+                            </Show>
+                            <CountdownValue value=minutes />
+                            ":"
+                            <CountdownValue value=secs />
+                        </Countdown>
+                    }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+/// A single labelled column of a [`CountdownTimer`]
+#[component]
+fn CountdownSegment(value: Signal<u8>, label: &'static str) -> impl IntoView {
+    view! {
+        <div class="flex flex-col">
+            <Countdown class="font-mono">
+                <CountdownValue value=value />
+            </Countdown>
+            {label}
+        </div>
+    }
+}
+
+/// Handle for controlling a [`CountdownTimer`] from outside the component
+///
+/// Create one with [`CountdownController::new`], pass it to the timer's `controller`
+/// prop and keep a copy to call [`pause`](Self::pause), [`resume`](Self::resume) or
+/// [`reset`](Self::reset) from buttons or other components.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CountdownController {
+    /// Whether the timer is currently paused
+    paused: RwSignal<bool>,
+
+    /// Time left until the countdown finishes
+    remaining: RwSignal<Duration>,
+
+    /// Incremented to restart the countdown from its full length
+    generation: RwSignal<usize>,
+}
+
+impl CountdownController {
+    /// Creates a controller for a running timer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the timer, keeping the remaining time
+    pub fn pause(&self) {
+        self.paused.set(true);
+    }
+
+    /// Continues a paused timer from where it stopped
+    pub fn resume(&self) {
+        self.paused.set(false);
+    }
+
+    /// Pauses a running timer or resumes a paused one
+    pub fn toggle(&self) {
+        self.paused.update(|paused| *paused = !*paused);
+    }
+
+    /// Restarts the countdown from its full length
+    pub fn reset(&self) {
+        self.generation.update(|n| *n = n.wrapping_add(1));
+    }
+
+    /// Whether the timer is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Time left until the countdown finishes
+    pub fn remaining(&self) -> Duration {
+        self.remaining.get()
+    }
+}
+
+/// Current time in milliseconds since the Unix epoch
+fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Instant `length` from now, in milliseconds since the Unix epoch
+fn deadline_after(length: Duration) -> f64 {
+    now() + length.as_secs_f64() * 1000.0
+}
+
+/// Converts a (possibly negative) millisecond difference into a `Duration`
+fn millis_to_duration(millis: f64) -> Duration {
+    Duration::from_millis(millis.max(0.0) as u64)
+}
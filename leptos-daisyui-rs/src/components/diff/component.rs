@@ -1,7 +1,9 @@
 use crate::merge_classes;
 use leptos::{
+    ev,
     html::{Div, Figure},
     prelude::*,
+    web_sys,
 };
 
 /// # Diff Component
@@ -9,15 +11,26 @@ use leptos::{
 /// A side-by-side comparison component that allows users to compare two items
 /// with an interactive resizer to adjust the view proportions.
 ///
+/// The split is controlled by the `position` signal (0–100%). The [`DiffResizer`] can be
+/// dragged with a mouse, pen or finger and moved with the arrow keys.
+///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("diff diff-item-1 diff-item-2 diff-resizer");
+/// @source inline("diff diff-item-1 diff-item-2 diff-resizer after:hidden focus-visible:opacity-100 focus-visible:bg-primary");
 /// ```
 ///
 /// ## Node References
 /// - `node_ref` - References the outer `<figure>` element ([HTMLElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement))
 #[component]
 pub fn Diff(
+    /// Split position as a percentage of the container, from 0 to 100 (defaults to 50)
+    #[prop(optional)]
+    position: Option<RwSignal<f64>>,
+
+    /// Stack the items vertically and split them with a horizontal resizer
+    #[prop(optional, into)]
+    vertical: Signal<bool>,
+
     /// Additional CSS classes to apply to the diff container
     #[prop(optional, into)]
     class: &'static str,
@@ -29,6 +42,11 @@ pub fn Diff(
     /// Child components: [`DiffItem1`], [`DiffItem2`], and [`DiffResizer`]
     children: Children,
 ) -> impl IntoView {
+    provide_context(DiffManager {
+        position: position.unwrap_or_else(|| RwSignal::new(50.0)),
+        vertical,
+    });
+
     view! {
         <figure node_ref=node_ref class=move || merge_classes!("diff", class)>
             {children()}
@@ -55,8 +73,24 @@ pub fn DiffItem1(
     /// Content for the first comparison item
     children: Children,
 ) -> impl IntoView {
+    let DiffManager { position, vertical } = DiffManager::expect_context();
+
     view! {
-        <div node_ref=node_ref class=move || merge_classes!("diff-item-1", class)>
+        <div
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "diff-item-1", if vertical.get() { "after:hidden" } else { "" }, class
+                )
+            }
+            style=move || {
+                if vertical.get() {
+                    format!("grid-column: 1 / -1; clip-path: inset(0 0 {}% 0);", 100.0 - position.get())
+                } else {
+                    String::new()
+                }
+            }
+        >
             {children()}
         </div>
     }
@@ -81,8 +115,14 @@ pub fn DiffItem2(
     /// Content for the second comparison item
     children: Children,
 ) -> impl IntoView {
+    let DiffManager { vertical, .. } = DiffManager::expect_context();
+
     view! {
-        <div node_ref=node_ref class=move || merge_classes!("diff-item-2", class)>
+        <div
+            node_ref=node_ref
+            class=move || merge_classes!("diff-item-2", class)
+            style=move || if vertical.get() { "grid-column: 1 / -1;" } else { "" }
+        >
             {children()}
         </div>
     }
@@ -93,6 +133,10 @@ pub fn DiffItem2(
 /// An interactive resizer element that allows users to adjust the proportions
 /// between the two diff items by dragging.
 ///
+/// Acts as a `slider`: arrow keys move the split by 1% (10% with `Shift`), `PageUp` and
+/// `PageDown` by 10%, and `Home`/`End` jump to the edges. Following the ARIA slider
+/// pattern, `ArrowRight`, `ArrowUp` and `PageUp` increase the position.
+///
 /// ## Node References
 /// - `node_ref` - References the resizer `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
 #[component]
@@ -105,5 +149,144 @@ pub fn DiffResizer(
     #[prop(optional)]
     node_ref: NodeRef<Div>,
 ) -> impl IntoView {
-    view! { <div node_ref=node_ref class=move || merge_classes!("diff-resizer", class)></div> }
+    let DiffManager { position, vertical } = DiffManager::expect_context();
+    let drag_start = StoredValue::new(None::<(f64, f64)>);
+
+    // Pointer coordinate along the split axis and the container size on that axis
+    let measure = move |ev: &ev::PointerEvent| {
+        let target = event_target::<web_sys::Element>(ev);
+        let rect = target.parent_element()?.get_bounding_client_rect();
+        Some(if vertical.get_untracked() {
+            (f64::from(ev.client_y()), rect.height())
+        } else {
+            (f64::from(ev.client_x()), rect.width())
+        })
+    };
+
+    let on_pointerdown = move |ev: ev::PointerEvent| {
+        let Some((coordinate, _)) = measure(&ev) else {
+            return;
+        };
+        ev.prevent_default();
+        let _ = event_target::<web_sys::Element>(&ev).set_pointer_capture(ev.pointer_id());
+        drag_start.set_value(Some((coordinate, position.get_untracked())));
+    };
+
+    let on_pointermove = move |ev: ev::PointerEvent| {
+        let Some((start, start_position)) = drag_start.get_value() else {
+            return;
+        };
+        let Some((coordinate, size)) = measure(&ev) else {
+            return;
+        };
+        if size > 0.0 {
+            position.set((start_position + (coordinate - start) / size * 100.0).clamp(0.0, 100.0));
+        }
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let step = if ev.shift_key() { 10.0 } else { 1.0 };
+        let Some(next) = key_position(
+            &ev.key(),
+            position.get_untracked(),
+            step,
+            vertical.get_untracked(),
+        ) else {
+            return;
+        };
+        ev.prevent_default();
+        position.set(next);
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "diff-resizer", "focus-visible:opacity-100", "focus-visible:bg-primary", class
+                )
+            }
+            role="slider"
+            tabindex="0"
+            aria-label="Comparison position"
+            aria-orientation=move || if vertical.get() { "vertical" } else { "horizontal" }
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow=move || position.get().round().to_string()
+            aria-valuetext=move || format!("{}%", position.get().round())
+            style=move || {
+                let position = position.get();
+                if vertical.get() {
+                    format!(
+                        "position: absolute; inset-inline: 0; top: {position}%; width: 100%; \
+                         max-width: none; height: 0.5rem; transform: translateY(-50%); \
+                         opacity: 1; resize: none; touch-action: none; cursor: ns-resize; \
+                         background: var(--color-base-100);"
+                    )
+                } else {
+                    format!("width: {position}cqi; resize: none; touch-action: none;")
+                }
+            }
+            on:pointerdown=on_pointerdown
+            on:pointermove=on_pointermove
+            on:pointerup=move |_| drag_start.set_value(None)
+            on:pointercancel=move |_| drag_start.set_value(None)
+            on:keydown=on_keydown
+        ></div>
+    }
+}
+
+/// Split position after pressing `key` on the resizer, or `None` if the key is not handled
+///
+/// The position grows rightwards when horizontal and downwards when vertical, so the
+/// arrow and page keys move the resizer in the direction they point.
+pub(crate) fn key_position(key: &str, current: f64, step: f64, vertical: bool) -> Option<f64> {
+    let next = match (key, vertical) {
+        ("Home", _) => 0.0,
+        ("End", _) => 100.0,
+        ("ArrowRight" | "ArrowUp", false) | ("ArrowDown", true) => current + step,
+        ("ArrowLeft" | "ArrowDown", false) | ("ArrowUp", true) => current - step,
+        ("PageUp", false) | ("PageDown", true) => current + 10.0,
+        ("PageDown", false) | ("PageUp", true) => current - 10.0,
+        _ => return None,
+    };
+    Some(next.clamp(0.0, 100.0))
+}
+
+/// Shared split state provided by [`Diff`] to its items and resizer
+#[derive(Clone)]
+pub(crate) struct DiffManager {
+    /// Split position as a percentage of the container
+    position: RwSignal<f64>,
+
+    /// Whether the items are stacked vertically
+    vertical: Signal<bool>,
+}
+
+impl DiffManager {
+    /// Retrieves the DiffManager from context.
+    pub fn expect_context() -> Self {
+        expect_context()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_follow_horizontal_resizer() {
+        assert_eq!(key_position("ArrowRight", 50.0, 1.0, false), Some(51.0));
+        assert_eq!(key_position("ArrowLeft", 50.0, 1.0, false), Some(49.0));
+        assert_eq!(key_position("PageUp", 95.0, 1.0, false), Some(100.0));
+        assert_eq!(key_position("ArrowUp", 50.0, 1.0, false), Some(51.0));
+    }
+
+    #[test]
+    fn keys_follow_vertical_resizer() {
+        assert_eq!(key_position("ArrowUp", 50.0, 10.0, true), Some(40.0));
+        assert_eq!(key_position("ArrowDown", 50.0, 10.0, true), Some(60.0));
+        assert_eq!(key_position("PageUp", 5.0, 1.0, true), Some(0.0));
+        assert_eq!(key_position("ArrowRight", 50.0, 1.0, true), None);
+    }
 }
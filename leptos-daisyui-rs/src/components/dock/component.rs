@@ -1,9 +1,16 @@
-use super::style::DockSize;
+#[cfg(feature = "router")]
+use super::super::utils::use_route_active;
+use super::{
+    super::utils::{IconSlot, RouteMatch, icon_slot},
+    style::DockSize,
+};
 use crate::merge_classes;
 use leptos::{
     html::{Button, Div, Span},
     prelude::*,
 };
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
 
 /// # Dock Component
 ///
@@ -53,14 +60,34 @@ pub fn Dock(
 /// An individual navigation button within the dock. Can be marked as active
 /// and supports click handling.
 ///
+/// Passing an `href` renders the item as a link instead. With the `router` feature
+/// enabled, that link uses `leptos_router` and the item is active while the current
+/// location matches it; the dock must then sit inside a `<Router>`.
+///
 /// ## Node References
-/// - `node_ref` - References the item `<button>` element ([HTMLButtonElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLButtonElement))
+/// - `node_ref` - References the item `<button>` element ([HTMLButtonElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLButtonElement)); not attached when `href` is set
 #[component]
 pub fn DockItem(
     /// Whether this dock item is currently active/selected
     #[prop(optional, into)]
     active: Signal<bool>,
 
+    /// Optional URL to navigate to, rendering the item as a link
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+
+    /// How `href` is compared with the current route (`router` feature only)
+    #[prop(optional, into)]
+    route_match: Signal<RouteMatch>,
+
+    /// Icon shown above the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown below the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Additional CSS classes to apply to the dock item
     #[prop(optional, into)]
     class: &'static str,
@@ -72,11 +99,48 @@ pub fn DockItem(
     /// Content including icons and [`DockLabel`]
     children: Children,
 ) -> impl IntoView {
+    let content = view! {
+        {icon_slot(move || icon_start.get(), || "1.2em")}
+        {children()}
+        {icon_slot(move || icon_end.get(), || "1.2em")}
+    };
+
+    if href.get_untracked().is_some() {
+        #[cfg(feature = "router")]
+        {
+            let href = Signal::derive(move || href.get().unwrap_or_default());
+            let route_active = use_route_active(href, route_match);
+
+            return view! {
+                <RouterLink
+                    href=move || href.get()
+                    {..}
+                    class=class
+                    class:dock-active=move || active.get() || route_active.get()
+                >
+                    {content}
+                </RouterLink>
+            }
+            .into_any();
+        }
+
+        #[cfg(not(feature = "router"))]
+        return view! {
+            <a href=move || href.get() class=class class:dock-active=active>
+                {content}
+            </a>
+        }
+        .into_any();
+    }
+    #[cfg(not(feature = "router"))]
+    let _ = route_match;
+
     view! {
         <button node_ref=node_ref class=class class:dock-active=active>
-            {children()}
+            {content}
         </button>
     }
+    .into_any()
 }
 
 /// # Dock Label Component
@@ -1,8 +1,13 @@
-use super::style::DrawerPlacement;
+use super::{
+    super::utils::{unique_id, use_focus_trap, use_scroll_lock},
+    style::{DrawerOpenBreakpoint, DrawerPlacement},
+};
 use crate::merge_classes;
 use leptos::{
-    html::{Div, Input},
+    ev,
+    html::{Div, Input, Label},
     prelude::*,
+    web_sys,
 };
 
 /// # Drawer Component
@@ -10,9 +15,12 @@ use leptos::{
 /// A grid layout that can show/hide a sidebar on the left or right side of the page.
 /// The drawer provides a slide-out navigation or content panel.
 ///
+/// The open state is kept in sync with the hidden [`DrawerToggle`] checkbox, so
+/// closing the drawer through [`DrawerOverlay`] or a `<label for=...>` also updates `open`.
+///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("drawer drawer-toggle drawer-content drawer-side drawer-overlay drawer-end drawer-open");
+/// @source inline("drawer drawer-toggle drawer-content drawer-side drawer-overlay drawer-end drawer-open sm:drawer-open md:drawer-open lg:drawer-open xl:drawer-open 2xl:drawer-open");
 /// ```
 ///
 /// ## Node References
@@ -23,9 +31,28 @@ pub fn Drawer(
     #[prop(optional, into)]
     placement: Signal<DrawerPlacement>,
 
-    /// Whether the drawer is open
+    /// Two-way signal for whether the drawer is open
+    #[prop(optional)]
+    open: RwSignal<bool>,
+
+    /// Keeps the sidebar visible from the given breakpoint upwards
+    #[prop(optional, into)]
+    open_from: Signal<DrawerOpenBreakpoint>,
+
+    /// ID of the toggle checkbox, generated automatically when omitted
+    ///
+    /// Set this when a `<label for=...>` outside of the drawer needs to toggle it.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Whether pressing Escape closes the drawer
+    #[prop(optional, into)]
+    close_on_escape: Signal<bool>,
+
+    /// Whether the drawer closes when a link inside [`DrawerSide`] is clicked
+    /// or the browser history changes
     #[prop(optional, into)]
-    open: Signal<bool>,
+    close_on_navigate: Signal<bool>,
 
     /// Additional CSS classes to apply to the drawer container
     #[prop(optional, into)]
@@ -38,6 +65,31 @@ pub fn Drawer(
     /// Child components: [`DrawerToggle`], [`DrawerContent`], [`DrawerSide`]
     children: Children,
 ) -> impl IntoView {
+    let id = id.unwrap_or_else(|| unique_id("drawer"));
+
+    provide_context(DrawerManager {
+        id,
+        open,
+        close_on_navigate,
+    });
+
+    let escape_handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() == "Escape" && close_on_escape.get_untracked() && open.get_untracked() {
+            open.set(false);
+        }
+    });
+
+    let popstate_handle = window_event_listener(ev::popstate, move |_| {
+        if close_on_navigate.get_untracked() && open.get_untracked() {
+            open.set(false);
+        }
+    });
+
+    on_cleanup(move || {
+        escape_handle.remove();
+        popstate_handle.remove();
+    });
+
     view! {
         <div
             node_ref=node_ref
@@ -45,10 +97,10 @@ pub fn Drawer(
                 merge_classes!(
                     "drawer",
                     placement.get().as_str(),
+                    open_from.get().as_str(),
                     class
                 )
             }
-            class:drawer-open=open
         >
             {children()}
         </div>
@@ -57,26 +109,28 @@ pub fn Drawer(
 
 /// # Drawer Toggle Component
 ///
-/// A hidden checkbox that controls the drawer open/close state. Use labels
-/// with matching `for` attributes to toggle the drawer.
+/// A hidden checkbox that controls the drawer open/close state. It is bound to the
+/// `open` signal of the parent [`Drawer`] and uses the drawer's ID, so labels with
+/// a matching `for` attribute toggle the drawer.
 ///
 /// ## Node References
 /// - `node_ref` - References the toggle `<input>` element ([HTMLInputElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement))
 #[component]
 pub fn DrawerToggle(
-    /// Unique ID for the checkbox, used by labels to control drawer state
-    id: &'static str,
-
-    /// Whether the drawer is currently open
-    #[prop(optional, into)]
-    checked: Signal<bool>,
-
     /// Node reference for the toggle `<input>` element
     #[prop(optional)]
     node_ref: NodeRef<Input>,
 ) -> impl IntoView {
+    let DrawerManager { id, open, .. } = DrawerManager::expect_context();
+
     view! {
-        <input node_ref=node_ref id=id type="checkbox" class="drawer-toggle" prop:checked=checked />
+        <input
+            node_ref=node_ref
+            id=id
+            type="checkbox"
+            class="drawer-toggle"
+            bind:checked=open
+        />
     }
 }
 
@@ -127,8 +181,32 @@ pub fn DrawerSide(
     /// Sidebar content (usually menu components)
     children: Children,
 ) -> impl IntoView {
+    let DrawerManager {
+        open,
+        close_on_navigate,
+        ..
+    } = DrawerManager::expect_context();
+
+    use_focus_trap(node_ref, open);
+    use_scroll_lock(open);
+
+    let on_click = move |ev: ev::MouseEvent| {
+        if !close_on_navigate.get_untracked() {
+            return;
+        }
+
+        let target = event_target::<web_sys::Element>(&ev);
+        if let Ok(Some(_)) = target.closest("a[href]") {
+            open.set(false);
+        }
+    };
+
     view! {
-        <div node_ref=node_ref class=move || merge_classes!("drawer-side", class)>
+        <div
+            node_ref=node_ref
+            class=move || merge_classes!("drawer-side", class)
+            on:click=on_click
+        >
             {children()}
         </div>
     }
@@ -137,19 +215,46 @@ pub fn DrawerSide(
 /// # Drawer Overlay Component
 ///
 /// An optional overlay that appears over the content when the drawer is open,
-/// typically used for mobile layouts to darken the background.
+/// typically used for mobile layouts to darken the background. Clicking it closes the drawer.
 ///
 /// ## Node References
-/// - `node_ref` - References the overlay `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+/// - `node_ref` - References the overlay `<label>` element ([HTMLLabelElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLabelElement))
 #[component]
 pub fn DrawerOverlay(
     /// Additional CSS classes to apply to the overlay
     #[prop(optional, into)]
     class: &'static str,
 
-    /// Node reference for the overlay `<div>` element
+    /// Node reference for the overlay `<label>` element
     #[prop(optional)]
-    node_ref: NodeRef<Div>,
+    node_ref: NodeRef<Label>,
 ) -> impl IntoView {
-    view! { <div node_ref=node_ref class=move || merge_classes!("drawer-overlay", class)></div> }
+    let DrawerManager { id, .. } = DrawerManager::expect_context();
+
+    view! {
+        <label
+            node_ref=node_ref
+            for=id
+            aria-label="close sidebar"
+            class=move || merge_classes!("drawer-overlay", class)
+        ></label>
+    }
+}
+
+/// Internal context manager for drawer state.
+#[derive(Clone)]
+pub(crate) struct DrawerManager {
+    /// ID shared by the toggle checkbox and the labels that control it
+    id: String,
+    /// Signal tracking whether the drawer is open
+    open: RwSignal<bool>,
+    /// Whether link clicks inside the sidebar close the drawer
+    close_on_navigate: Signal<bool>,
+}
+
+impl DrawerManager {
+    /// Retrieves the DrawerManager from context.
+    pub fn expect_context() -> Self {
+        expect_context()
+    }
 }
@@ -21,3 +21,47 @@ impl DrawerPlacement {
         }
     }
 }
+
+/// # Drawer Open Breakpoint Variants
+///
+/// Style enum for daisyUI `drawer-open` classes that keep the sidebar visible
+/// without toggling, optionally starting from a responsive breakpoint.
+#[derive(Clone, Debug, Default)]
+pub enum DrawerOpenBreakpoint {
+    /// Sidebar is only visible when toggled (default)
+    #[default]
+    Never,
+
+    /// Sidebar is always visible
+    Always,
+
+    /// Sidebar is always visible from the `sm` breakpoint upwards
+    Sm,
+
+    /// Sidebar is always visible from the `md` breakpoint upwards
+    Md,
+
+    /// Sidebar is always visible from the `lg` breakpoint upwards
+    Lg,
+
+    /// Sidebar is always visible from the `xl` breakpoint upwards
+    Xl,
+
+    /// Sidebar is always visible from the `2xl` breakpoint upwards
+    Xxl,
+}
+
+impl DrawerOpenBreakpoint {
+    /// CSS class string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DrawerOpenBreakpoint::Never => "",
+            DrawerOpenBreakpoint::Always => "drawer-open",
+            DrawerOpenBreakpoint::Sm => "sm:drawer-open",
+            DrawerOpenBreakpoint::Md => "md:drawer-open",
+            DrawerOpenBreakpoint::Lg => "lg:drawer-open",
+            DrawerOpenBreakpoint::Xl => "xl:drawer-open",
+            DrawerOpenBreakpoint::Xxl => "2xl:drawer-open",
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::style::*;
+use super::{super::utils::use_focus_trap, style::*};
 use crate::merge_classes;
 use leptos::{
     html::{Details, Div, Summary, Ul},
@@ -45,6 +45,8 @@ pub fn Dropdown(
     /// Dropdown content
     children: Children,
 ) -> impl IntoView {
+    use_focus_trap(node_ref, open);
+
     view! {
         <div
             node_ref=node_ref
@@ -101,6 +103,8 @@ pub fn DropdownDetails(
     /// Dropdown content
     children: Children,
 ) -> impl IntoView {
+    use_focus_trap(node_ref, open);
+
     view! {
         <details
             node_ref=node_ref
@@ -1,12 +1,29 @@
-use super::style::{FileInputColor, FileInputSize, FileInputStyle};
+use super::{
+    super::{
+        avatar::{Avatar, AvatarModifier},
+        mask::{Mask, MaskType},
+        progress::{Progress, ProgressColor},
+        validator::ValidatorHint,
+    },
+    style::{FileInputColor, FileInputSize, FileInputStyle},
+};
 use crate::merge_classes;
-use leptos::{html::Input, prelude::*};
+use leptos::{
+    ev,
+    html::{Div, Input},
+    prelude::*,
+    web_sys::{self, File, FileList, Url},
+};
+use std::collections::HashMap;
 
 /// # File Input Component
 ///
 /// A reactive Leptos wrapper for daisyUI's file input component that provides a styled
 /// file upload interface with customizable appearance and behavior.
 ///
+/// Selected files are written to the optional `files` signal. Setting the signal to an
+/// empty list clears the input.
+///
 /// ### Add to `input.css`
 /// ```css
 /// @source inline("file-input file-input-xs file-input-sm file-input-md file-input-lg file-input-xl file-input-ghost file-input-neutral file-input-primary file-input-secondary file-input-accent file-input-info file-input-success file-input-warning file-input-error");
@@ -32,14 +49,35 @@ pub fn FileInput(
     #[prop(optional, into)]
     class: &'static str,
 
+    /// Files currently selected in the input
+    #[prop(optional)]
+    files: Option<RwSignal<Vec<File>, LocalStorage>>,
+
     /// Node reference to the input element
     #[prop(optional)]
     node_ref: NodeRef<Input>,
 ) -> impl IntoView {
+    if let Some(files) = files {
+        Effect::new(move || {
+            let Some(input) = node_ref.get() else { return };
+            if files.with(Vec::is_empty) {
+                input.set_value("");
+            }
+        });
+    }
+
+    let on_change = move |ev: ev::Event| {
+        if let Some(files) = files {
+            let input = event_target::<web_sys::HtmlInputElement>(&ev);
+            files.set(file_list_to_vec(input.files()));
+        }
+    };
+
     view! {
         <input
             node_ref=node_ref
             type="file"
+            on:change=on_change
             class=move || {
                 merge_classes!(
                     "file-input",
@@ -52,3 +90,351 @@ pub fn FileInput(
         />
     }
 }
+
+/// # File Drop Zone Component
+///
+/// A drag-and-drop upload area bound to a `files` signal. Files can be dropped onto the
+/// zone or picked with the browser's file dialog. Each file is checked against `accept`,
+/// `max_size` and `max_count`; rejected files are listed in a [`ValidatorHint`].
+///
+/// Accepted files are shown in a removable list with image thumbnails and an optional
+/// per-file [`Progress`] bar, driven by the `progress` map (index in `files` to percent).
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("avatar avatar-placeholder mask mask-squircle progress progress-primary validator-text btn btn-ghost btn-xs btn-square border-2 border-dashed border-base-300 border-primary bg-primary/10 rounded-box sr-only");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the drop zone container `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn FileDropZone(
+    /// Accepted files
+    files: RwSignal<Vec<File>, LocalStorage>,
+
+    /// Comma separated file types, e.g. `"image/*,.pdf"` (same syntax as the `accept` attribute)
+    #[prop(optional)]
+    accept: Option<&'static str>,
+
+    /// Maximum size of a single file in bytes
+    #[prop(optional)]
+    max_size: Option<u64>,
+
+    /// Maximum number of files; `1` replaces the current file instead of adding to it
+    #[prop(optional)]
+    max_count: Option<usize>,
+
+    /// Upload progress keyed by the index of the file in `files`, from 0 to 100
+    #[prop(optional, into)]
+    progress: Signal<HashMap<usize, f64>>,
+
+    /// Additional CSS classes to apply to the drop area
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the container `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+
+    /// Content of the drop area (defaults to a short instruction)
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let dragging = RwSignal::new(false);
+    // Entering a child element fires `dragleave` on the parent, so count nested enters
+    let drag_depth = StoredValue::new(0usize);
+    let errors = RwSignal::new(Vec::<String>::new());
+
+    let add_files = move |incoming: Vec<File>| {
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+        let existing = if max_count == Some(1) {
+            0
+        } else {
+            files.with_untracked(Vec::len)
+        };
+
+        for file in incoming {
+            let name = file.name();
+            let error = if accept.is_some_and(|accept| !accepts_file(accept, &name, &file.type_()))
+            {
+                Some("file type is not accepted".to_string())
+            } else if max_size.is_some_and(|max| file.size() > max as f64) {
+                let max = max_size.unwrap_or_default() as f64;
+                Some(format!("larger than {}", format_file_size(max)))
+            } else if max_count.is_some_and(|max| existing + accepted.len() >= max) {
+                let max = max_count.unwrap_or_default();
+                Some(format!("no more than {max} files allowed"))
+            } else {
+                None
+            };
+
+            match error {
+                Some(error) => rejected.push(format!("{name}: {error}")),
+                None => accepted.push(file),
+            }
+        }
+
+        errors.set(rejected);
+        if max_count == Some(1) && !accepted.is_empty() {
+            files.set(accepted);
+        } else {
+            files.update(|files| files.extend(accepted));
+        }
+    };
+
+    let on_drop = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        drag_depth.set_value(0);
+        dragging.set(false);
+        add_files(file_list_to_vec(
+            ev.data_transfer().and_then(|data| data.files()),
+        ));
+    };
+
+    let on_change = move |ev: ev::Event| {
+        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+        add_files(file_list_to_vec(input.files()));
+        // Allow picking the same file again after removing it
+        input.set_value("");
+    };
+
+    let file_list = move || {
+        files.with(|files| {
+            files
+                .iter()
+                .enumerate()
+                .map(|(index, file)| {
+                    let name = file.name();
+                    let preview = file
+                        .type_()
+                        .starts_with("image/")
+                        .then(|| Url::create_object_url_with_blob(file).ok())
+                        .flatten();
+                    view! {
+                        <FileDropZoneItem
+                            index=index
+                            name=name
+                            size=file.size()
+                            preview=preview
+                            progress=progress
+                            on_remove=Callback::new(move |_| {
+                                files.update(|files| {
+                                    if index < files.len() {
+                                        files.remove(index);
+                                    }
+                                });
+                            })
+                        />
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div node_ref=node_ref class="flex flex-col gap-2">
+            <label
+                class=move || {
+                    merge_classes!(
+                        "flex flex-col items-center justify-center gap-2 p-6 text-center cursor-pointer border-2 border-dashed rounded-box",
+                        if dragging.get() { "border-primary bg-primary/10" } else { "border-base-300" },
+                        class
+                    )
+                }
+                on:dragenter=move |ev: ev::DragEvent| {
+                    ev.prevent_default();
+                    drag_depth.update_value(|depth| *depth += 1);
+                    dragging.set(true);
+                }
+                on:dragover=move |ev: ev::DragEvent| ev.prevent_default()
+                on:dragleave=move |_| {
+                    drag_depth.update_value(|depth| *depth = depth.saturating_sub(1));
+                    if drag_depth.get_value() == 0 {
+                        dragging.set(false);
+                    }
+                }
+                on:drop=on_drop
+            >
+                <input
+                    type="file"
+                    class="sr-only"
+                    accept=accept
+                    multiple={max_count != Some(1)}
+                    on:change=on_change
+                />
+                {match children {
+                    Some(children) => children().into_any(),
+                    None => view! { <span>"Drop files here or click to browse"</span> }.into_any(),
+                }}
+            </label>
+            <Show when=move || errors.with(|errors| !errors.is_empty())>
+                <ValidatorHint class="text-error">
+                    <ul>
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|error| view! { <li>{error}</li> })
+                                .collect_view()
+                        }}
+                    </ul>
+                </ValidatorHint>
+            </Show>
+            <ul class="flex flex-col gap-2">{file_list}</ul>
+        </div>
+    }
+}
+
+/// A single row of the [`FileDropZone`] file list
+#[component]
+fn FileDropZoneItem(
+    index: usize,
+    name: String,
+    size: f64,
+    preview: Option<String>,
+    progress: Signal<HashMap<usize, f64>>,
+    on_remove: Callback<()>,
+) -> impl IntoView {
+    if let Some(url) = preview.clone() {
+        on_cleanup(move || {
+            let _ = Url::revoke_object_url(&url);
+        });
+    }
+
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_uppercase())
+        .unwrap_or_default();
+    let value = move || progress.with(|progress| progress.get(&index).copied());
+
+    view! {
+        <li class="flex items-center gap-3">
+            {match preview {
+                Some(url) => view! {
+                    <Avatar>
+                        <Mask mask_type=MaskType::Squircle class="w-12 h-12">
+                            <img src=url alt="" />
+                        </Mask>
+                    </Avatar>
+                }
+                .into_any(),
+                None => view! {
+                    <Avatar modifier=AvatarModifier::Placeholder>
+                        <Mask
+                            mask_type=MaskType::Squircle
+                            class="w-12 h-12 bg-neutral text-neutral-content"
+                        >
+                            <span class="text-xs">{extension}</span>
+                        </Mask>
+                    </Avatar>
+                }
+                .into_any(),
+            }}
+            <div class="flex flex-1 flex-col gap-1 min-w-0">
+                <div class="flex justify-between gap-2 text-sm">
+                    <span class="truncate">{name}</span>
+                    <span class="opacity-60">{format_file_size(size)}</span>
+                </div>
+                {move || {
+                    value()
+                        .map(|value| {
+                            view! {
+                                <Progress
+                                    color=ProgressColor::Primary
+                                    class="w-full"
+                                    attr:value=value
+                                    attr:max=100.0
+                                />
+                            }
+                        })
+                }}
+            </div>
+            <button
+                type="button"
+                class="btn btn-ghost btn-xs btn-square"
+                aria-label="Remove file"
+                on:click=move |_| on_remove.run(())
+            >
+                "✕"
+            </button>
+        </li>
+    }
+}
+
+/// Collects the files of a `FileList` into a `Vec`
+fn file_list_to_vec(list: Option<FileList>) -> Vec<File> {
+    let Some(list) = list else {
+        return Vec::new();
+    };
+
+    (0..list.length())
+        .filter_map(|index| list.item(index))
+        .collect()
+}
+
+/// Checks a file against an `accept` attribute value
+///
+/// Supports file extensions (`.pdf`), exact MIME types (`image/png`) and MIME
+/// wildcards (`image/*`), matching case-insensitively.
+pub(crate) fn accepts_file(accept: &str, name: &str, mime: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime = mime.to_lowercase();
+
+    accept
+        .split(',')
+        .map(|token| token.trim().to_lowercase())
+        .filter(|token| !token.is_empty())
+        .any(|token| {
+            if token.starts_with('.') {
+                name.ends_with(&token)
+            } else if let Some(prefix) = token.strip_suffix("/*") {
+                mime.split_once('/').is_some_and(|(kind, _)| kind == prefix)
+            } else {
+                mime == token
+            }
+        })
+}
+
+/// Formats a byte count for display, e.g. `1.5 MB`
+pub(crate) fn format_file_size(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024.0 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_extensions_and_mime_types() {
+        assert!(accepts_file(
+            ".pdf, image/*",
+            "Report.PDF",
+            "application/pdf"
+        ));
+        assert!(accepts_file(".pdf, image/*", "photo.jpg", "image/jpeg"));
+        assert!(accepts_file("text/plain", "notes.txt", "text/plain"));
+        assert!(!accepts_file(".pdf, image/*", "notes.txt", "text/plain"));
+        assert!(!accepts_file("", "notes.txt", "text/plain"));
+    }
+
+    #[test]
+    fn formats_file_sizes() {
+        assert_eq!(format_file_size(512.0), "512 B");
+        assert_eq!(format_file_size(1536.0), "1.5 KB");
+        assert_eq!(format_file_size(5.0 * 1024.0 * 1024.0), "5.0 MB");
+    }
+}
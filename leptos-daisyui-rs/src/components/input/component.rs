@@ -1,15 +1,29 @@
-use super::style::{InputColor, InputSize, InputStyle};
+use super::{
+    super::{
+        kbd::{Kbd, KbdSize},
+        utils::{IconSlot, icon_slot},
+    },
+    style::{InputColor, InputSize, InputStyle},
+};
 use crate::merge_classes;
-use leptos::{html::Input as HtmlInput, prelude::*};
+use leptos::{
+    attribute_interceptor::AttributeInterceptor,
+    html::{Input as HtmlInput, Label},
+    prelude::*,
+};
 
 /// # Input Component
 ///
 /// A reactive Leptos wrapper for daisyUI's input component that provides styled
 /// text input fields with customizable size, color, and style.
 ///
+/// When `icon_start` or `icon_end` is set (`icons` feature), the input is wrapped in a
+/// `<label class="input">` holding the icons. Attributes spread onto the component are
+/// still forwarded to the `<input>`.
+///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("input input-neutral input-primary input-secondary input-accent input-info input-success input-warning input-error input-ghost input-xs input-sm input-md input-lg input-xl");
+/// @source inline("input input-neutral input-primary input-secondary input-accent input-info input-success input-warning input-error input-ghost input-xs input-sm input-md input-lg input-xl grow");
 /// ```
 ///
 /// ## Node References
@@ -28,6 +42,14 @@ pub fn Input(
     #[prop(optional, into)]
     size: Signal<InputSize>,
 
+    /// Icon shown before the input (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the input (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -36,8 +58,142 @@ pub fn Input(
     #[prop(optional)]
     node_ref: NodeRef<HtmlInput>,
 ) -> impl IntoView {
+    let input_class = move || {
+        merge_classes!(
+            "input",
+            style.get().as_str(),
+            color.get().as_str(),
+            size.get().as_str(),
+            class
+        )
+    };
+
+    if icon_start.get_untracked().is_some() || icon_end.get_untracked().is_some() {
+        return view! {
+            <AttributeInterceptor let:attrs>
+                <label class=input_class>
+                    {icon_slot(move || icon_start.get(), move || size.get().icon_size())}
+                    <input node_ref=node_ref class="grow" {..attrs} />
+                    {icon_slot(move || icon_end.get(), move || size.get().icon_size())}
+                </label>
+            </AttributeInterceptor>
+        }
+        .into_any();
+    }
+
     view! {
         <input
+            node_ref=node_ref
+            class=input_class
+        />
+    }
+    .into_any()
+}
+
+/// # Input Group Component
+///
+/// An input wrapped in daisyUI's `<label class="input">` with adornments: `prefix` and
+/// `suffix` content, `Kbd` hints, a clear button resetting `value`, a password visibility
+/// toggle using daisyUI's swap classes, and a character counter when `maxlength` is set.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("input input-neutral input-primary input-secondary input-accent input-info input-success input-warning input-error input-ghost input-xs input-sm input-md input-lg input-xl grow kbd kbd-sm swap swap-on swap-off swap-active btn btn-ghost btn-xs btn-circle text-xs opacity-50 opacity-60 tabular-nums");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wrapping `<label>` element ([HTMLLabelElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLabelElement))
+/// - `input_node_ref` - References the `<input>` element ([HTMLInputElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement))
+#[component]
+pub fn InputGroup(
+    /// Bound input value
+    #[prop(optional)]
+    value: RwSignal<String>,
+
+    /// Input type, `text` by default; ignored when `password` is set
+    #[prop(optional, into)]
+    input_type: MaybeProp<String>,
+
+    /// Placeholder text
+    #[prop(optional, into)]
+    placeholder: MaybeProp<String>,
+
+    /// Form field name
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+
+    /// Whether the input is disabled
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Renders a password input with a visibility toggle
+    #[prop(optional)]
+    password: bool,
+
+    /// Shows a button clearing the value while it is not empty
+    #[prop(optional, into)]
+    clearable: Signal<bool>,
+
+    /// Maximum number of characters, shown as a counter
+    #[prop(optional)]
+    maxlength: Option<usize>,
+
+    /// Content before the input (icons, text)
+    #[prop(optional, into)]
+    prefix: Option<ViewFn>,
+
+    /// Content after the input (units, buttons)
+    #[prop(optional, into)]
+    suffix: Option<ViewFn>,
+
+    /// Keys shown as `Kbd` hints at the end, e.g. `["⌘", "K"]`
+    #[prop(optional, into)]
+    kbd: Vec<&'static str>,
+
+    /// Input style variant
+    #[prop(optional, into)]
+    style: Signal<InputStyle>,
+
+    /// Input color variant
+    #[prop(optional, into)]
+    color: Signal<InputColor>,
+
+    /// Input size variant
+    #[prop(optional, into)]
+    size: Signal<InputSize>,
+
+    /// Additional CSS classes for the wrapping label
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wrapping `<label>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Label>,
+
+    /// Node reference for the `<input>` element
+    #[prop(optional)]
+    input_node_ref: NodeRef<HtmlInput>,
+) -> impl IntoView {
+    let revealed = RwSignal::new(false);
+    let icon_size = move || size.get().icon_size();
+
+    let current_type = move || {
+        if password {
+            if revealed.get() { "text" } else { "password" }.to_string()
+        } else {
+            input_type.get().unwrap_or_else(|| "text".to_string())
+        }
+    };
+
+    let on_clear = move |_| {
+        value.set(String::new());
+        if let Some(input) = input_node_ref.get_untracked() {
+            let _ = input.focus();
+        }
+    };
+
+    view! {
+        <label
             node_ref=node_ref
             class=move || {
                 merge_classes!(
@@ -48,6 +204,96 @@ pub fn Input(
                     class
                 )
             }
-        />
+        >
+            {prefix.map(|prefix| prefix.run())}
+            <input
+                node_ref=input_node_ref
+                class="grow"
+                type=current_type
+                placeholder=move || placeholder.get()
+                name=move || name.get()
+                maxlength=maxlength
+                disabled=disabled
+                bind:value=value
+            />
+            {move || {
+                (clearable.get() && value.with(|value| !value.is_empty()))
+                    .then(|| {
+                        view! {
+                            <button
+                                type="button"
+                                class="btn btn-ghost btn-xs btn-circle"
+                                aria-label="Clear"
+                                on:click=on_clear
+                            >
+                                "✕"
+                            </button>
+                        }
+                    })
+            }}
+            {maxlength
+                .map(|max| {
+                    view! {
+                        <span class="text-xs opacity-60 tabular-nums">
+                            {move || value.with(|value| value.chars().count())} "/" {max}
+                        </span>
+                    }
+                })}
+            {password
+                .then(|| {
+                    view! {
+                        <button
+                            type="button"
+                            class="swap btn btn-ghost btn-xs btn-circle"
+                            class:swap-active=revealed
+                            aria-label="Toggle password visibility"
+                            aria-pressed=move || revealed.get().to_string()
+                            on:click=move |_| revealed.update(|revealed| *revealed = !*revealed)
+                        >
+                            <svg
+                                class="swap-on"
+                                viewBox="0 0 24 24"
+                                width=icon_size
+                                height=icon_size
+                                fill="none"
+                                stroke="currentColor"
+                                stroke-width="2"
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                            >
+                                <path d="M3 3l18 18" />
+                                <path d="M10.6 5.1A10 10 0 0 1 12 5c6.5 0 10 7 10 7a17 17 0 0 1-3.2 4.1M6.6 6.6C3.8 8.4 2 12 2 12s3.5 7 10 7a9.7 9.7 0 0 0 5.4-1.6" />
+                                <path d="M9.9 9.9a3 3 0 0 0 4.2 4.2" />
+                            </svg>
+                            <svg
+                                class="swap-off"
+                                viewBox="0 0 24 24"
+                                width=icon_size
+                                height=icon_size
+                                fill="none"
+                                stroke="currentColor"
+                                stroke-width="2"
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                            >
+                                <path d="M2 12s3.5-7 10-7 10 7 10 7-3.5 7-10 7S2 12 2 12z" />
+                                <circle cx="12" cy="12" r="3" />
+                            </svg>
+                        </button>
+                    }
+                })}
+            {suffix.map(|suffix| suffix.run())}
+            {(!kbd.is_empty())
+                .then(|| {
+                    view! {
+                        <span class="opacity-50">
+                            {kbd
+                                .into_iter()
+                                .map(|key| view! { <Kbd size=KbdSize::Sm>{key}</Kbd> })
+                                .collect_view()}
+                        </span>
+                    }
+                })}
+        </label>
     }
 }
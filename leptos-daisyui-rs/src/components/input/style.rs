@@ -108,4 +108,15 @@ impl InputSize {
             InputSize::Xl => "input-xl",
         }
     }
+
+    /// CSS width and height of icons in slots of this size
+    pub fn icon_size(&self) -> &'static str {
+        match self {
+            InputSize::Xs => "0.75rem",
+            InputSize::Sm => "0.875rem",
+            InputSize::Md => "1rem",
+            InputSize::Lg => "1.25rem",
+            InputSize::Xl => "1.5rem",
+        }
+    }
 }
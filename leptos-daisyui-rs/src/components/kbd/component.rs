@@ -1,6 +1,12 @@
-use super::style::KbdSize;
+use super::{
+    super::utils::{Hotkey, is_apple_platform},
+    style::KbdSize,
+};
 use crate::merge_classes;
-use leptos::{html::Kbd as HtmlKbd, prelude::*};
+use leptos::{
+    html::{Kbd as HtmlKbd, Span},
+    prelude::*,
+};
 
 /// # Kbd Component
 ///
@@ -44,3 +50,70 @@ pub fn Kbd(
         </kbd>
     }
 }
+
+/// # Shortcut Component
+///
+/// Renders a shortcut definition like `"Mod+Shift+K"` as a sequence of [`Kbd`] keys.
+/// `Mod` is shown as `⌘` on Apple platforms and `Ctrl` elsewhere, and modifiers use
+/// their Apple symbols there. Pass the same definition to
+/// [`use_hotkey`](crate::utils::use_hotkey) to bind it.
+///
+/// The platform is detected once mounted in the browser, so server-rendered output
+/// uses the non-Apple labels.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("kbd kbd-xs kbd-sm kbd-md kbd-lg kbd-xl inline-flex items-center gap-1");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wrapping `<span>` element ([HTMLSpanElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLSpanElement))
+#[component]
+pub fn Shortcut(
+    /// Shortcut definition such as `"Mod+K"`
+    #[prop(into)]
+    keys: Signal<String>,
+
+    /// Size of the keys
+    #[prop(optional, into)]
+    size: Signal<KbdSize>,
+
+    /// Additional CSS classes to apply to the wrapping element
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wrapping `<span>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Span>,
+) -> impl IntoView {
+    let apple = RwSignal::new(false);
+    Effect::new(move |_| apple.set(is_apple_platform()));
+
+    let labels = move || {
+        keys.with(|keys| match Hotkey::parse(keys) {
+            Some(hotkey) => hotkey.labels(apple.get()),
+            None => vec![keys.clone()],
+        })
+    };
+
+    view! {
+        <span
+            node_ref=node_ref
+            class=move || merge_classes!("inline-flex items-center gap-1", class)
+        >
+            {move || {
+                let separator = !apple.get();
+                labels()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, label)| {
+                        view! {
+                            {(separator && index > 0).then_some("+")}
+                            <Kbd size=size>{label}</Kbd>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </span>
+    }
+}
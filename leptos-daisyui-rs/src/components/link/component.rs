@@ -1,12 +1,17 @@
 use super::style::LinkColor;
 use crate::merge_classes;
 use leptos::{html::A, prelude::*};
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
 
 /// # Link Component
 ///
 /// A reactive Leptos wrapper for daisyUI's link component that provides styled
 /// anchor elements with customizable colors and hover effects.
 ///
+/// With the `router` feature enabled, links with an `href` navigate client-side through
+/// `leptos_router`'s `<A>`, which panics outside a `<Router>`.
+///
 /// ### Add to `input.css`
 /// ```css
 /// @source inline("link link-neutral link-primary link-secondary link-accent link-success link-info link-warning link-error link-hover");
@@ -39,6 +44,22 @@ pub fn Link(
     /// Text content of the link
     children: Children,
 ) -> impl IntoView {
+    #[cfg(feature = "router")]
+    if let Some(href) = href {
+        return view! {
+            <RouterLink
+                href=href
+                {..}
+                node_ref=node_ref
+                class=move || merge_classes!("link", color.get().as_str(), class)
+                class:link-hover=hover
+            >
+                {children()}
+            </RouterLink>
+        }
+        .into_any();
+    }
+
     let href_value = href.unwrap_or("javascript:void(0)");
 
     view! {
@@ -55,4 +76,5 @@ pub fn Link(
             {children()}
         </a>
     }
+    .into_any()
 }
@@ -1,9 +1,20 @@
-use super::style::{MenuDirection, MenuSize};
+#[cfg(feature = "router")]
+use super::super::utils::use_route_active;
+use super::{
+    super::utils::{IconSlot, RouteMatch, icon_slot},
+    style::{MenuDirection, MenuSize},
+};
 use crate::merge_classes;
 use leptos::{
+    ev,
     html::{H2, Li, Ul},
     prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{self, Element, HtmlDetailsElement, HtmlElement},
 };
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
+use std::collections::HashSet;
 
 /// # Menu Component
 ///
@@ -71,6 +82,10 @@ pub fn Menu(
 /// A reactive Leptos wrapper for individual menu items with selection tracking
 /// and navigation support.
 ///
+/// With the `router` feature enabled, items with an `href` navigate through `leptos_router`
+/// and, unless the menu is manual, are marked active from the current location using
+/// `route_match`. Menus with such items need an enclosing `<Router>`.
+///
 /// ## Node References
 /// - `node_ref` - References the li element ([HTMLLIElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLIElement))
 #[component]
@@ -91,6 +106,18 @@ pub fn MenuItem(
     #[prop(optional, into)]
     disabled: Signal<bool>,
 
+    /// How `href` is compared with the current route (`router` feature only)
+    #[prop(optional, into)]
+    route_match: Signal<RouteMatch>,
+
+    /// Icon shown before the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -121,23 +148,54 @@ pub fn MenuItem(
         *selected = Some(value.get_untracked());
     };
 
+    #[cfg(feature = "router")]
+    let route_active = (!is_submenu && !href.get_untracked().is_empty())
+        .then(|| use_route_active(href, route_match));
+    #[cfg(not(feature = "router"))]
+    let _ = route_match;
+
     let is_active = move || {
         if manual {
             return active.get();
         }
 
+        #[cfg(feature = "router")]
+        if let Some(route_active) = route_active {
+            return route_active.get();
+        }
+
         selected
             .get()
             .as_ref()
             .is_some_and(|s| s == &value.get_untracked())
     };
 
+    let link_content = move |children: Children| {
+        view! {
+            {icon_slot(move || icon_start.get(), || "1em")}
+            {children()}
+            {icon_slot(move || icon_end.get(), || "1em")}
+        }
+    };
+
+    #[cfg(feature = "router")]
+    if route_active.is_some() {
+        return view! {
+            <li node_ref=node_ref on:click=on_click class=class>
+                <RouterLink href=move || href.get() {..} class:menu-active=is_active>
+                    {link_content(children)}
+                </RouterLink>
+            </li>
+        }
+        .into_any();
+    }
+
     view! {
         <li node_ref=node_ref on:click=on_click class=class>
             {if !is_submenu {
                 view! {
                     <a href=href class:menu-active=is_active>
-                        {children()}
+                        {link_content(children)}
                     </a>
                 }
                     .into_any()
@@ -147,6 +205,7 @@ pub fn MenuItem(
 
         </li>
     }
+    .into_any()
 }
 
 /// # Menu Title Component
@@ -203,6 +262,378 @@ pub fn SubMenu(
     }
 }
 
+/// # Menu Tree Component
+///
+/// A data-driven menu rendered from a tree of [`MenuNode`]s. Nodes with children become
+/// collapsible `<details>` submenus whose open state is kept in the `expanded` signal, and
+/// the ancestors of the `selected` item are expanded automatically.
+///
+/// Supports keyboard navigation: `ArrowUp`/`ArrowDown` move between visible items,
+/// `Home`/`End` jump to the first and last item, `ArrowRight` opens a submenu or enters
+/// it, and `ArrowLeft` closes it or moves to the parent submenu.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("menu menu-title menu-dropdown menu-dropdown-toggle menu-disabled menu-active menu-focus menu-dropdown-show menu-xs menu-sm menu-md menu-lg menu-xl menu-vertical menu-horizontal badge badge-sm");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the ul element ([HTMLUListElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLUListElement))
+#[component]
+pub fn MenuTree(
+    /// Tree of menu items to render
+    #[prop(into)]
+    nodes: Signal<Vec<MenuNode>>,
+
+    /// Signal for tracking the currently selected menu item value
+    #[prop(optional)]
+    selected: RwSignal<Option<String>>,
+
+    /// Values of the submenus that are currently open
+    #[prop(optional)]
+    expanded: RwSignal<HashSet<String>>,
+
+    /// Called with the value of an item when it is selected
+    #[prop(optional, into)]
+    on_select: Option<Callback<String>>,
+
+    /// Layout direction of menu items
+    #[prop(optional, into)]
+    direction: Signal<MenuDirection>,
+
+    /// Size variant for menu items
+    #[prop(optional, into)]
+    size: Signal<MenuSize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Reference to the ul element
+    #[prop(optional)]
+    node_ref: NodeRef<Ul>,
+) -> impl IntoView {
+    provide_context(MenuManager {
+        manual: false,
+        selected,
+    });
+
+    // Reveal the selected item by opening every submenu above it
+    Effect::new(move || {
+        let Some(value) = selected.get() else { return };
+        let Some(ancestors) = nodes.with(|nodes| menu_ancestors(nodes, &value)) else {
+            return;
+        };
+
+        if !expanded.with_untracked(|open| ancestors.iter().all(|value| open.contains(value))) {
+            expanded.update(|open| open.extend(ancestors));
+        }
+    });
+
+    let state = MenuTreeState {
+        selected,
+        expanded,
+        on_select,
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(menu) = node_ref.get_untracked() else {
+            return;
+        };
+        if navigate_menu(&menu, &ev.key()) {
+            ev.prevent_default();
+        }
+    };
+
+    view! {
+        <ul
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "menu",
+                    direction.get().as_str(),
+                    size.get().as_str(),
+                    class
+                )
+            }
+            on:keydown=on_keydown
+        >
+            {move || {
+                nodes
+                    .get()
+                    .into_iter()
+                    .map(|node| render_menu_node(node, state))
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+
+/// An item of a [`MenuTree`]
+///
+/// The `value` identifies the item for selection and for the expanded state of its
+/// submenu, so it should be unique within the tree.
+#[derive(Clone, Default)]
+pub struct MenuNode {
+    /// Text shown for the item
+    label: String,
+
+    /// Identifier used for selection and expansion tracking
+    value: String,
+
+    /// Optional URL for navigation
+    href: Option<String>,
+
+    /// Icon rendered before the label
+    icon: Option<ViewFn>,
+
+    /// Badge text rendered after the label
+    badge: Option<String>,
+
+    /// Whether the item can be selected
+    disabled: bool,
+
+    /// Items of the submenu
+    children: Vec<MenuNode>,
+}
+
+impl MenuNode {
+    /// Creates an item with a label and a unique value
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the URL the item links to
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Sets an icon rendered before the label
+    pub fn icon(mut self, icon: impl Into<ViewFn>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets a badge rendered after the label
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    /// Prevents the item from being selected
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Sets the items of the submenu
+    pub fn children(mut self, children: Vec<MenuNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Signals shared by every item of a [`MenuTree`]
+#[derive(Clone, Copy)]
+struct MenuTreeState {
+    selected: RwSignal<Option<String>>,
+    expanded: RwSignal<HashSet<String>>,
+    on_select: Option<Callback<String>>,
+}
+
+fn render_menu_node(node: MenuNode, state: MenuTreeState) -> AnyView {
+    let MenuNode {
+        label,
+        value,
+        href,
+        icon,
+        badge,
+        disabled,
+        children,
+    } = node;
+
+    let content = view! {
+        {icon.map(|icon| icon.run())}
+        {label}
+        {badge.map(|badge| view! { <span class="badge badge-sm">{badge}</span> })}
+    };
+
+    if children.is_empty() {
+        let item_value = value.clone();
+        let is_active = move || {
+            state
+                .selected
+                .with(|selected| selected.as_ref() == Some(&value))
+        };
+        let on_click = move |ev: ev::MouseEvent| {
+            if disabled {
+                ev.prevent_default();
+                return;
+            }
+
+            state.selected.set(Some(item_value.clone()));
+            if let Some(on_select) = state.on_select {
+                on_select.run(item_value.clone());
+            }
+        };
+
+        // Items without a URL are buttons so Enter and Space select them
+        let item = match href {
+            Some(href) => view! {
+                <a
+                    href=href
+                    aria-disabled=disabled.then_some("true")
+                    class:menu-active=is_active
+                    on:click=on_click
+                >
+                    {content}
+                </a>
+            }
+            .into_any(),
+            None => view! {
+                <button
+                    type="button"
+                    aria-disabled=disabled.then_some("true")
+                    class:menu-active=is_active
+                    on:click=on_click
+                >
+                    {content}
+                </button>
+            }
+            .into_any(),
+        };
+
+        view! { <li class:menu-disabled=disabled>{item}</li> }.into_any()
+    } else {
+        let toggle_value = value.clone();
+        let is_open = move || state.expanded.with(|expanded| expanded.contains(&value));
+        let on_toggle = move |ev: ev::Event| {
+            let open = event_target::<HtmlDetailsElement>(&ev).open();
+            if open
+                != state
+                    .expanded
+                    .with_untracked(|expanded| expanded.contains(&toggle_value))
+            {
+                state.expanded.update(|expanded| {
+                    if open {
+                        expanded.insert(toggle_value.clone());
+                    } else {
+                        expanded.remove(&toggle_value);
+                    }
+                });
+            }
+        };
+
+        view! {
+            <li class:menu-disabled=disabled>
+                <details prop:open=is_open on:toggle=on_toggle>
+                    <summary>{content}</summary>
+                    <ul>
+                        {children
+                            .into_iter()
+                            .map(|child| render_menu_node(child, state))
+                            .collect_view()}
+                    </ul>
+                </details>
+            </li>
+        }
+        .into_any()
+    }
+}
+
+/// Values of the submenus containing the item with `value`, outermost first
+///
+/// Returns `None` if no item in the tree has the given value.
+pub(crate) fn menu_ancestors(nodes: &[MenuNode], value: &str) -> Option<Vec<String>> {
+    nodes.iter().find_map(|node| {
+        if node.value == value {
+            return Some(Vec::new());
+        }
+
+        let mut path = menu_ancestors(&node.children, value)?;
+        path.insert(0, node.value.clone());
+        Some(path)
+    })
+}
+
+/// Moves focus between the visible items of a menu; returns whether `key` was handled
+fn navigate_menu(menu: &Element, key: &str) -> bool {
+    let items = visible_menu_items(menu);
+    let Some(active) = document().active_element() else {
+        return false;
+    };
+    let index = items.iter().position(|item| {
+        let item: &Element = item.as_ref();
+        item == &active
+    });
+    let is_summary = active.tag_name() == "SUMMARY";
+    let details = active
+        .parent_element()
+        .and_then(|parent| parent.dyn_into::<HtmlDetailsElement>().ok());
+
+    let focus = |item: Option<&HtmlElement>| {
+        if let Some(item) = item {
+            let _ = item.focus();
+        }
+    };
+
+    match key {
+        "ArrowDown" => focus(match index {
+            Some(index) => items.get(index + 1),
+            None => items.first(),
+        }),
+        "ArrowUp" => focus(match index {
+            Some(index) => items.get(index.saturating_sub(1)),
+            None => items.last(),
+        }),
+        "Home" => focus(items.first()),
+        "End" => focus(items.last()),
+        "ArrowRight" => match details.filter(|_| is_summary) {
+            Some(details) if !details.open() => details.set_open(true),
+            Some(_) => focus(index.and_then(|index| items.get(index + 1))),
+            None => return false,
+        },
+        "ArrowLeft" => match details.filter(|details| is_summary && details.open()) {
+            Some(details) => details.set_open(false),
+            None => {
+                // Move to the summary of the submenu containing the focused item
+                let item = if is_summary {
+                    active.parent_element()
+                } else {
+                    Some(active)
+                };
+                let parent_summary = item
+                    .and_then(|item| item.parent_element())
+                    .and_then(|li| li.closest("details").ok().flatten())
+                    .and_then(|details| details.query_selector(":scope > summary").ok().flatten())
+                    .and_then(|summary| summary.dyn_into::<HtmlElement>().ok());
+                focus(parent_summary.as_ref());
+            }
+        },
+        _ => return false,
+    }
+
+    true
+}
+
+/// Items and submenu summaries of a menu that are currently rendered
+fn visible_menu_items(menu: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = menu.query_selector_all("a, button, summary") else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|item| item.get_client_rects().length() > 0)
+        .collect()
+}
+
 /// Internal context manager for menu selection state.
 #[derive(Clone)]
 pub(crate) struct MenuManager {
@@ -218,3 +649,36 @@ impl MenuManager {
         expect_context()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Vec<MenuNode> {
+        vec![
+            MenuNode::new("Home", "home"),
+            MenuNode::new("Docs", "docs").children(vec![
+                MenuNode::new("Intro", "intro"),
+                MenuNode::new("Guides", "guides").children(vec![MenuNode::new("Setup", "setup")]),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn menu_ancestors_of_top_level_item_is_empty() {
+        assert_eq!(menu_ancestors(&tree(), "home"), Some(vec![]));
+    }
+
+    #[test]
+    fn menu_ancestors_lists_parents_outermost_first() {
+        assert_eq!(
+            menu_ancestors(&tree(), "setup"),
+            Some(vec!["docs".to_string(), "guides".to_string()])
+        );
+    }
+
+    #[test]
+    fn menu_ancestors_of_unknown_item_is_none() {
+        assert_eq!(menu_ancestors(&tree(), "missing"), None);
+    }
+}
@@ -1,8 +1,11 @@
+use super::style::{CodeLanguage, CodePrefix, TokenKind};
 use crate::merge_classes;
 use leptos::{
     html::{Div, Pre},
     prelude::*,
+    wasm_bindgen::{JsValue, closure::Closure},
 };
+use std::time::Duration;
 
 /// # Mockup Code Component
 ///
@@ -66,3 +69,446 @@ pub fn MockupCodeLine(
         </pre>
     }
 }
+
+/// # Code Block Component
+///
+/// A [`MockupCode`] rendered from a source string. The source is split into lines,
+/// prefixed with line numbers or shell prompts and highlighted for the given language.
+/// Lines listed in `highlight_lines` (1-based) get a `bg-warning` row, and a copy button
+/// writes the source to the clipboard.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("mockup-code relative bg-warning text-warning-content btn btn-ghost btn-xs absolute top-2 right-2 text-info text-success text-warning text-secondary text-accent opacity-60 italic");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the div element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn CodeBlock(
+    /// Source code to display
+    #[prop(into)]
+    code: Signal<String>,
+
+    /// Language used for syntax highlighting
+    #[prop(optional, into)]
+    language: Signal<CodeLanguage>,
+
+    /// Prefix shown in front of each line
+    #[prop(optional, into)]
+    prefix: Signal<CodePrefix>,
+
+    /// 1-based numbers of lines to highlight
+    #[prop(optional, into)]
+    highlight_lines: Signal<Vec<usize>>,
+
+    /// Whether to hide the copy button
+    #[prop(optional, into)]
+    hide_copy: Signal<bool>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the div element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let copied = RwSignal::new(false);
+
+    let copy = move |_| {
+        let promise = window()
+            .navigator()
+            .clipboard()
+            .write_text(&code.get_untracked());
+        let on_copied = Closure::once(move |_: JsValue| {
+            let _ = copied.try_set(true);
+            set_timeout(
+                move || {
+                    let _ = copied.try_set(false);
+                },
+                Duration::from_secs(2),
+            );
+        });
+        let _ = promise.then(&on_copied);
+        // runs at most once, when the clipboard write succeeds
+        on_copied.forget();
+    };
+
+    let lines = move || {
+        let language = language.get();
+        let prefix = prefix.get();
+        let highlighted = highlight_lines.get();
+
+        code.with(|code| {
+            code.lines()
+                .enumerate()
+                .map(|(index, line)| {
+                    let (data_prefix, line, language) = match prefix {
+                        CodePrefix::LineNumbers => (Some((index + 1).to_string()), line, language),
+                        CodePrefix::Prompt => match split_prompt(line) {
+                            Some(command) => (Some("$".to_string()), command, language),
+                            None => (Some(">".to_string()), line, CodeLanguage::Plain),
+                        },
+                        CodePrefix::None => (None, line, language),
+                    };
+                    let marked = highlighted.contains(&(index + 1));
+                    let tokens = highlight_line(line, language)
+                        .into_iter()
+                        .map(|(kind, text)| view! { <span class=kind.as_str()>{text}</span> })
+                        .collect_view();
+
+                    view! {
+                        <pre
+                            data-prefix=data_prefix
+                            class:bg-warning=marked
+                            class:text-warning-content=marked
+                        >
+                            <code>{tokens}</code>
+                        </pre>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("mockup-code", "relative", class)>
+            {move || {
+                (!hide_copy.get())
+                    .then(|| {
+                        view! {
+                            <button
+                                type="button"
+                                class="btn btn-ghost btn-xs absolute top-2 right-2"
+                                aria-label="Copy code"
+                                on:click=copy
+                            >
+                                {move || if copied.get() { "Copied!" } else { "Copy" }}
+                            </button>
+                        }
+                    })
+            }}
+            {lines}
+        </div>
+    }
+}
+
+/// Command of a shell line written as `$ command`, or `None` for output lines
+pub(crate) fn split_prompt(line: &str) -> Option<&str> {
+    if line == "$" {
+        Some("")
+    } else {
+        line.strip_prefix("$ ")
+    }
+}
+
+/// Rust keywords highlighted as [`TokenKind::Keyword`]
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "type", "unsafe", "use",
+    "where", "while",
+];
+
+/// Shell keywords highlighted as [`TokenKind::Keyword`]
+const SHELL_KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+    "function", "in", "export", "local", "return",
+];
+
+/// Splits one line of source code into highlighted tokens.
+///
+/// Adjacent text of the same kind is merged. Lines are highlighted independently, so
+/// constructs spanning several lines (block comments, multi-line strings) are not tracked.
+pub(crate) fn highlight_line(line: &str, language: CodeLanguage) -> Vec<(TokenKind, String)> {
+    let mut tokens = Vec::new();
+    if language == CodeLanguage::Plain {
+        push_token(&mut tokens, TokenKind::Plain, line);
+        return tokens;
+    }
+
+    let chars = line.chars().collect::<Vec<_>>();
+    let word_char = |c: char| {
+        c.is_alphanumeric()
+            || c == '_'
+            || (c == '-' && matches!(language, CodeLanguage::Toml | CodeLanguage::Shell))
+    };
+
+    let mut index = 0;
+    let mut command_start = true;
+    while index < chars.len() {
+        let start = index;
+        let current = chars[index];
+        let prev = index.checked_sub(1).map(|prev| chars[prev]);
+        let after_space = prev.is_none_or(char::is_whitespace);
+
+        let kind = if current.is_whitespace() {
+            index += 1;
+            TokenKind::Plain
+        } else if is_comment_start(language, &chars[index..], after_space) {
+            index = chars.len();
+            TokenKind::Comment
+        } else if current == '"'
+            || (current == '\'' && matches!(language, CodeLanguage::Toml | CodeLanguage::Shell))
+        {
+            index = scan_string(&chars, index);
+            if language == CodeLanguage::Json && next_non_space(&chars, index) == Some(':') {
+                TokenKind::Key
+            } else {
+                TokenKind::String
+            }
+        } else if current.is_ascii_digit() && !prev.is_some_and(word_char) {
+            index = scan_while(&chars, index, |c| {
+                c.is_ascii_alphanumeric() || c == '_' || c == '.'
+            });
+            TokenKind::Number
+        } else if language == CodeLanguage::Rust
+            && current == '#'
+            && matches!(chars.get(index + 1), Some('[' | '!'))
+        {
+            index = chars[index..]
+                .iter()
+                .position(|c| *c == ']')
+                .map_or(chars.len(), |end| index + end + 1);
+            TokenKind::Macro
+        } else if language == CodeLanguage::Toml && current == '[' && prev.is_none() {
+            index = chars.len();
+            TokenKind::Key
+        } else if language == CodeLanguage::Shell && current == '$' {
+            index = if chars.get(index + 1) == Some(&'{') {
+                chars[index..]
+                    .iter()
+                    .position(|c| *c == '}')
+                    .map_or(chars.len(), |end| index + end + 1)
+            } else {
+                scan_while(&chars, index + 1, |c| c.is_alphanumeric() || c == '_')
+            };
+            TokenKind::Type
+        } else if language == CodeLanguage::Shell && current == '-' && after_space {
+            index = scan_while(&chars, index, |c| !c.is_whitespace());
+            TokenKind::Literal
+        } else if current.is_alphabetic() || current == '_' {
+            index = scan_while(&chars, index, word_char);
+            let word = chars[start..index].iter().collect::<String>();
+            let next = chars.get(index).copied();
+
+            match language {
+                CodeLanguage::Rust if next == Some('!') => {
+                    index += 1;
+                    TokenKind::Macro
+                }
+                CodeLanguage::Rust => classify_rust(&word),
+                CodeLanguage::Toml if word == "true" || word == "false" => TokenKind::Literal,
+                CodeLanguage::Toml
+                    if !chars[..start].contains(&'=')
+                        && matches!(next_non_space(&chars, index), Some('=' | '.')) =>
+                {
+                    TokenKind::Key
+                }
+                CodeLanguage::Json if matches!(word.as_str(), "true" | "false" | "null") => {
+                    TokenKind::Literal
+                }
+                CodeLanguage::Shell if command_start || SHELL_KEYWORDS.contains(&word.as_str()) => {
+                    TokenKind::Keyword
+                }
+                _ => TokenKind::Plain,
+            }
+        } else {
+            index += 1;
+            TokenKind::Plain
+        };
+
+        if !current.is_whitespace() {
+            command_start = matches!(current, '|' | ';' | '&' | '(');
+        }
+
+        let text = chars[start..index].iter().collect::<String>();
+        push_token(&mut tokens, kind, &text);
+    }
+
+    tokens
+}
+
+/// Classifies a Rust identifier
+fn classify_rust(word: &str) -> TokenKind {
+    if RUST_KEYWORDS.contains(&word) {
+        TokenKind::Keyword
+    } else if word == "true" || word == "false" {
+        TokenKind::Literal
+    } else if word.starts_with(char::is_uppercase) {
+        TokenKind::Type
+    } else {
+        TokenKind::Plain
+    }
+}
+
+/// Whether a line comment starts at the beginning of `rest`
+fn is_comment_start(language: CodeLanguage, rest: &[char], after_space: bool) -> bool {
+    match language {
+        CodeLanguage::Rust => rest.starts_with(&['/', '/']),
+        CodeLanguage::Toml => rest.starts_with(&['#']),
+        CodeLanguage::Shell => rest.starts_with(&['#']) && after_space,
+        CodeLanguage::Json | CodeLanguage::Plain => false,
+    }
+}
+
+/// Index just past the string literal starting at `start`
+fn scan_string(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut index = start + 1;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' if quote == '"' => index += 2,
+            c if c == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index of the first character from `start` not matching `predicate`
+fn scan_while(chars: &[char], start: usize, predicate: impl Fn(char) -> bool) -> usize {
+    chars[start..]
+        .iter()
+        .position(|c| !predicate(*c))
+        .map_or(chars.len(), |end| start + end)
+}
+
+/// First non-whitespace character from `start`
+fn next_non_space(chars: &[char], start: usize) -> Option<char> {
+    chars
+        .get(start..)?
+        .iter()
+        .copied()
+        .find(|c| !c.is_whitespace())
+}
+
+/// Appends text to the token list, merging it with the previous token of the same kind
+fn push_token(tokens: &mut Vec<(TokenKind, String)>, kind: TokenKind, text: &str) {
+    match tokens.last_mut() {
+        Some((last, last_text)) if *last == kind => last_text.push_str(text),
+        _ if text.is_empty() => {}
+        _ => tokens.push((kind, text.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds_of(line: &str, language: CodeLanguage) -> Vec<(TokenKind, String)> {
+        let tokens = highlight_line(line, language);
+        let joined = tokens
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect::<String>();
+        assert_eq!(joined, line, "tokens must cover the whole line");
+        tokens
+            .into_iter()
+            .filter(|(kind, _)| *kind != TokenKind::Plain)
+            .collect()
+    }
+
+    fn token(kind: TokenKind, text: &str) -> (TokenKind, String) {
+        (kind, text.to_string())
+    }
+
+    #[test]
+    fn highlights_rust() {
+        assert_eq!(
+            kinds_of("let x: Vec<u8> = vec![42]; // answer", CodeLanguage::Rust),
+            vec![
+                token(TokenKind::Keyword, "let"),
+                token(TokenKind::Type, "Vec"),
+                token(TokenKind::Macro, "vec!"),
+                token(TokenKind::Number, "42"),
+                token(TokenKind::Comment, "// answer"),
+            ]
+        );
+        assert_eq!(
+            kinds_of(
+                "#[derive(Clone)] println!(\"a \\\" b\")",
+                CodeLanguage::Rust
+            ),
+            vec![
+                token(TokenKind::Macro, "#[derive(Clone)]"),
+                token(TokenKind::Macro, "println!"),
+                token(TokenKind::String, "\"a \\\" b\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlights_toml() {
+        assert_eq!(
+            kinds_of("[dependencies]", CodeLanguage::Toml),
+            vec![token(TokenKind::Key, "[dependencies]")]
+        );
+        assert_eq!(
+            kinds_of(
+                "leptos = { version = \"0.8\", optional = true } # ui",
+                CodeLanguage::Toml
+            ),
+            vec![
+                token(TokenKind::Key, "leptos"),
+                token(TokenKind::String, "\"0.8\""),
+                token(TokenKind::Literal, "true"),
+                token(TokenKind::Comment, "# ui"),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlights_shell() {
+        assert_eq!(
+            kinds_of(
+                "cargo build --release | grep \"a # b\" $HOME # done",
+                CodeLanguage::Shell
+            ),
+            vec![
+                token(TokenKind::Keyword, "cargo"),
+                token(TokenKind::Literal, "--release"),
+                token(TokenKind::Keyword, "grep"),
+                token(TokenKind::String, "\"a # b\""),
+                token(TokenKind::Type, "$HOME"),
+                token(TokenKind::Comment, "# done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlights_json() {
+        assert_eq!(
+            kinds_of(
+                "{\"name\": \"leptos\", \"stars\": 1.5e3, \"fork\": null}",
+                CodeLanguage::Json
+            ),
+            vec![
+                token(TokenKind::Key, "\"name\""),
+                token(TokenKind::String, "\"leptos\""),
+                token(TokenKind::Key, "\"stars\""),
+                token(TokenKind::Number, "1.5e3"),
+                token(TokenKind::Key, "\"fork\""),
+                token(TokenKind::Literal, "null"),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_plain_text_alone() {
+        assert_eq!(
+            highlight_line("let x = 1;", CodeLanguage::Plain),
+            vec![token(TokenKind::Plain, "let x = 1;")]
+        );
+        assert!(highlight_line("", CodeLanguage::Rust).is_empty());
+    }
+
+    #[test]
+    fn splits_shell_prompts() {
+        assert_eq!(split_prompt("$ cargo build"), Some("cargo build"));
+        assert_eq!(split_prompt("$"), Some(""));
+        assert_eq!(split_prompt("   Compiling leptos"), None);
+        assert_eq!(split_prompt("$HOME"), None);
+    }
+}
@@ -3,5 +3,7 @@
 //! For more information, see: https://daisyui.com/components/mockup-code/
 
 mod component;
+mod style;
 
 pub use component::*;
+pub use style::*;
@@ -0,0 +1,88 @@
+/// # Code Language Variants
+///
+/// Language used by [`CodeBlock`](super::CodeBlock) to pick syntax highlighting rules.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CodeLanguage {
+    /// No highlighting (default)
+    #[default]
+    Plain,
+
+    /// Rust source code
+    Rust,
+
+    /// TOML configuration such as `Cargo.toml`
+    Toml,
+
+    /// Shell commands
+    Shell,
+
+    /// JSON documents
+    Json,
+}
+
+/// # Code Prefix Variants
+///
+/// Controls the `data-prefix` shown in front of each [`CodeBlock`](super::CodeBlock) line.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CodePrefix {
+    /// Line numbers starting at 1 (default)
+    #[default]
+    LineNumbers,
+
+    /// `$` for lines starting with `$ `, which is stripped, and `>` for output lines
+    Prompt,
+
+    /// No prefix
+    None,
+}
+
+/// # Code Token Kinds
+///
+/// Kinds of tokens [`CodeBlock`](super::CodeBlock) highlights code with, mapped to
+/// text color classes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Unhighlighted text
+    Plain,
+
+    /// Language keywords and shell commands
+    Keyword,
+
+    /// String literals
+    String,
+
+    /// Numeric literals
+    Number,
+
+    /// Comments
+    Comment,
+
+    /// Booleans, `null` and shell flags
+    Literal,
+
+    /// Table headers and object keys
+    Key,
+
+    /// Type names and shell variables
+    Type,
+
+    /// Macros and attributes
+    Macro,
+}
+
+impl TokenKind {
+    /// CSS class string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenKind::Plain => "",
+            TokenKind::Keyword => "text-info",
+            TokenKind::String => "text-success",
+            TokenKind::Number => "text-warning",
+            TokenKind::Comment => "opacity-60 italic",
+            TokenKind::Literal => "text-secondary",
+            TokenKind::Key => "text-accent",
+            TokenKind::Type => "text-accent",
+            TokenKind::Macro => "text-secondary",
+        }
+    }
+}
@@ -60,6 +60,10 @@ mod toast;
 mod toggle;
 mod validator;
 
+// Components reach the utilities through `super::super::utils`, which also resolves when
+// the CLI copies a component next to `generated/utils.rs`.
+use crate::utils;
+
 pub use accordion::*;
 pub use alert::*;
 pub use avatar::*;
@@ -1,3 +1,4 @@
+use super::super::utils::{use_focus_trap, use_scroll_lock};
 use crate::merge_classes;
 use leptos::{
     html::{Dialog, Div, Form},
@@ -37,6 +38,9 @@ pub fn Modal(
     /// Modal content
     children: Children,
 ) -> impl IntoView {
+    use_focus_trap(node_ref, open);
+    use_scroll_lock(open);
+
     Effect::new(move || {
         let Some(node) = node_ref.get() else { return };
 
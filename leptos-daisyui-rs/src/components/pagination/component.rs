@@ -1,4 +1,7 @@
-use super::style::PaginationSize;
+use super::{
+    super::select::{Select, SelectOption},
+    style::PaginationSize,
+};
 use crate::merge_classes;
 use leptos::{
     html::{Button, Div, Input},
@@ -124,3 +127,337 @@ pub fn PaginationInput(
         />
     }
 }
+
+/// # Paginator Component
+///
+/// A complete pagination control driven by a `current_page` signal. Computes the visible
+/// page numbers from `total_items` and `page_size`, collapsing long ranges with ellipses,
+/// and optionally renders first/last buttons, a jump-to-page input and a page-size select.
+///
+/// Pages are numbered from 1.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("join join-xs join-sm join-md join-lg join-item btn btn-active btn-disabled btn-error flex flex-wrap items-center gap-2 w-20 w-auto");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the container div element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn Paginator(
+    /// Currently selected page, starting at 1
+    current_page: RwSignal<usize>,
+
+    /// Total number of items being paginated
+    #[prop(into)]
+    total_items: Signal<usize>,
+
+    /// Number of items per page (defaults to 10)
+    #[prop(optional)]
+    page_size: Option<RwSignal<usize>>,
+
+    /// Choices for the page-size select; the select is hidden when empty
+    #[prop(optional, into)]
+    page_sizes: Signal<Vec<usize>>,
+
+    /// Number of pages shown on each side of the current page (defaults to 1)
+    #[prop(optional)]
+    siblings: Option<usize>,
+
+    /// Number of pages always shown at the start and end (defaults to 1)
+    #[prop(optional)]
+    boundaries: Option<usize>,
+
+    /// Show buttons that jump to the first and last page
+    #[prop(optional, into)]
+    show_edges: Signal<bool>,
+
+    /// Show an input for jumping directly to a page number
+    #[prop(optional, into)]
+    show_jump: Signal<bool>,
+
+    /// Size of the pagination controls
+    #[prop(optional, into)]
+    size: Signal<PaginationSize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the container element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let page_size = page_size.unwrap_or_else(|| RwSignal::new(10));
+    let siblings = siblings.unwrap_or(1);
+    let boundaries = boundaries.unwrap_or(1);
+
+    let page_count = Memo::new(move |_| total_pages(total_items.get(), page_size.get()));
+
+    // Keep the current page in range when the item count or page size shrinks
+    Effect::new(move || {
+        let last = page_count.get();
+        let page = current_page.get();
+        if page > last || page == 0 {
+            current_page.set(page.clamp(1, last));
+        }
+    });
+
+    let go_to = move |page: usize| current_page.set(page.clamp(1, page_count.get_untracked()));
+    let is_first = Signal::derive(move || current_page.get() <= 1);
+    let is_last = Signal::derive(move || current_page.get() >= page_count.get());
+
+    let items = move || {
+        page_range(current_page.get(), page_count.get(), siblings, boundaries)
+            .into_iter()
+            .map(|item| match item {
+                PageItem::Page(page) => view! {
+                    <PaginationButton
+                        active=Signal::derive(move || current_page.get() == page)
+                        attr:aria-label=format!("Page {page}")
+                        attr:aria-current=move || (current_page.get() == page).then_some("page")
+                        on:click=move |_| go_to(page)
+                    >
+                        {page}
+                    </PaginationButton>
+                }
+                .into_any(),
+                PageItem::Ellipsis => view! {
+                    <PaginationButton disabled=true>"…"</PaginationButton>
+                }
+                .into_any(),
+            })
+            .collect_view()
+    };
+
+    let jump_invalid = RwSignal::new(false);
+    let on_jump = move |ev: leptos::ev::Event| {
+        let input = event_target_value(&ev);
+        match input.trim().parse::<usize>() {
+            Ok(page) if (1..=page_count.get_untracked()).contains(&page) => {
+                jump_invalid.set(false);
+                current_page.set(page);
+            }
+            _ => jump_invalid.set(true),
+        }
+    };
+
+    // Keep the first visible item on screen when the page size changes
+    let on_page_size = move |ev: leptos::ev::Event| {
+        let Ok(new_size) = event_target_value(&ev).parse::<usize>() else {
+            return;
+        };
+        let first_item = current_page.get_untracked().saturating_sub(1) * page_size.get_untracked();
+        page_size.set(new_size);
+        current_page.set(first_item / new_size.max(1) + 1);
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            role="navigation"
+            aria-label="Pagination"
+            class=move || merge_classes!("flex flex-wrap items-center gap-2", class)
+        >
+            <div class=move || merge_classes!("join", size.get().as_str())>
+                <Show when=move || show_edges.get()>
+                    <PaginationButton
+                        disabled=is_first
+                        attr:aria-label="First page"
+                        on:click=move |_| go_to(1)
+                    >
+                        "«"
+                    </PaginationButton>
+                </Show>
+                <PaginationButton
+                    disabled=is_first
+                    attr:aria-label="Previous page"
+                    on:click=move |_| go_to(current_page.get_untracked().saturating_sub(1))
+                >
+                    "‹"
+                </PaginationButton>
+                {items}
+                <PaginationButton
+                    disabled=is_last
+                    attr:aria-label="Next page"
+                    on:click=move |_| go_to(current_page.get_untracked() + 1)
+                >
+                    "›"
+                </PaginationButton>
+                <Show when=move || show_edges.get()>
+                    <PaginationButton
+                        disabled=is_last
+                        attr:aria-label="Last page"
+                        on:click=move |_| go_to(page_count.get_untracked())
+                    >
+                        "»"
+                    </PaginationButton>
+                </Show>
+            </div>
+            <Show when=move || show_jump.get()>
+                <div class=move || merge_classes!("join", size.get().as_str())>
+                    <PaginationInput
+                        class="w-20"
+                        value=Signal::derive(move || current_page.get().to_string())
+                        attr:inputmode="numeric"
+                        attr:aria-label="Go to page"
+                        attr:aria-invalid=move || jump_invalid.get().to_string()
+                        class:btn-error=jump_invalid
+                        on:change=on_jump
+                    />
+                </div>
+            </Show>
+            <Show when=move || !page_sizes.get().is_empty()>
+                <Select
+                    class="w-auto"
+                    attr:aria-label="Items per page"
+                    prop:value=move || page_size.get().to_string()
+                    on:change=on_page_size
+                >
+                    {move || {
+                        page_sizes
+                            .get()
+                            .into_iter()
+                            .map(|option| {
+                                view! {
+                                    <SelectOption
+                                        attr:value=option.to_string()
+                                        attr:selected=move || page_size.get() == option
+                                    >
+                                        {format!("{option} / page")}
+                                    </SelectOption>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </Select>
+            </Show>
+        </div>
+    }
+}
+
+/// An entry in the list of visible pagination controls
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PageItem {
+    /// A numbered page button
+    Page(usize),
+
+    /// A gap standing in for one or more hidden pages
+    Ellipsis,
+}
+
+/// Number of pages needed for `total_items`, never less than 1
+pub(crate) fn total_pages(total_items: usize, page_size: usize) -> usize {
+    total_items.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Computes the visible pagination entries around `current`
+///
+/// Always shows `boundaries` pages at each end and `siblings` pages on each side of
+/// `current`. Gaps are collapsed into [`PageItem::Ellipsis`] unless the gap is a single
+/// page, in which case that page is shown instead, so the number of entries stays
+/// constant while navigating.
+pub(crate) fn page_range(
+    current: usize,
+    total_pages: usize,
+    siblings: usize,
+    boundaries: usize,
+) -> Vec<PageItem> {
+    let (page, count) = (current as i64, total_pages as i64);
+    let (siblings, boundaries) = (siblings as i64, boundaries as i64);
+    let pages = |start: i64, end: i64| (start..=end).map(|page| PageItem::Page(page as usize));
+
+    let end_start = (count - boundaries + 1).max(boundaries + 1);
+    let siblings_start = (page - siblings)
+        .min(count - boundaries - siblings * 2 - 1)
+        .max(boundaries + 2);
+    let siblings_end = (page + siblings)
+        .max(boundaries + siblings * 2 + 2)
+        .min(end_start - 2);
+
+    let mut items: Vec<PageItem> = pages(1, boundaries.min(count)).collect();
+
+    if siblings_start > boundaries + 2 {
+        items.push(PageItem::Ellipsis);
+    } else if boundaries + 1 < count - boundaries {
+        items.push(PageItem::Page((boundaries + 1) as usize));
+    }
+
+    items.extend(pages(siblings_start, siblings_end));
+
+    if siblings_end < count - boundaries - 1 {
+        items.push(PageItem::Ellipsis);
+    } else if count - boundaries > boundaries {
+        items.push(PageItem::Page((count - boundaries) as usize));
+    }
+
+    items.extend(pages(end_start, count));
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PageItem::{Ellipsis, Page};
+    use super::*;
+
+    #[test]
+    fn total_pages_rounds_up() {
+        assert_eq!(total_pages(0, 10), 1);
+        assert_eq!(total_pages(10, 10), 1);
+        assert_eq!(total_pages(11, 10), 2);
+        assert_eq!(total_pages(5, 0), 5);
+    }
+
+    #[test]
+    fn page_range_without_gaps() {
+        assert_eq!(page_range(1, 1, 1, 1), vec![Page(1)]);
+        assert_eq!(
+            page_range(2, 5, 1, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+    }
+
+    #[test]
+    fn page_range_collapses_both_sides() {
+        assert_eq!(
+            page_range(5, 10, 1, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn page_range_keeps_length_at_edges() {
+        assert_eq!(
+            page_range(1, 10, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_range(10, 10, 1, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use super::style::{RangeColor, RangeSize};
 use crate::merge_classes;
-use leptos::{html::Input, prelude::*};
+use leptos::{
+    ev,
+    html::{Div, Input},
+    prelude::*,
+};
 
 /// # Range Component
 ///
@@ -47,3 +51,187 @@ pub fn Range(
         />
     }
 }
+
+/// # Range Slider Component
+///
+/// A dual-thumb slider selecting a `low`–`high` pair. Built from two overlaid daisyUI
+/// range inputs whose thumbs cannot cross, with optional value tooltips above the thumbs
+/// and a measurement row of tick marks and labels below the track.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("range range-neutral range-primary range-secondary range-accent range-success range-warning range-info range-error range-xs range-sm range-md range-lg range-xl badge badge-sm pointer-events-none [&::-webkit-slider-thumb]:pointer-events-auto [&::-moz-range-thumb]:pointer-events-auto");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the slider container `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn RangeSlider(
+    /// Lower end of the selected range
+    low: RwSignal<f64>,
+
+    /// Upper end of the selected range
+    high: RwSignal<f64>,
+
+    /// Smallest selectable value (defaults to 0)
+    #[prop(optional)]
+    min: Option<f64>,
+
+    /// Largest selectable value (defaults to 100)
+    #[prop(optional)]
+    max: Option<f64>,
+
+    /// Increment between selectable values (defaults to 1)
+    #[prop(optional)]
+    step: Option<f64>,
+
+    /// Number of evenly spaced tick marks below the track (at least 2 to be shown)
+    #[prop(optional)]
+    ticks: Option<usize>,
+
+    /// Show the tick values below the tick marks
+    #[prop(optional, into)]
+    tick_labels: Signal<bool>,
+
+    /// Show the current values above the thumbs
+    #[prop(optional, into)]
+    tooltip: Signal<bool>,
+
+    /// Formats values for tooltips and tick labels
+    #[prop(optional, into)]
+    format: Option<Callback<f64, String>>,
+
+    /// Color scheme of the range slider
+    #[prop(optional, into)]
+    color: Signal<RangeColor>,
+
+    /// Size of the range slider
+    #[prop(optional, into)]
+    size: Signal<RangeSize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the container element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let min = min.unwrap_or(0.0);
+    let max = max.unwrap_or(100.0);
+    let step = step.unwrap_or(1.0);
+
+    let format_value = move |value: f64| match format {
+        Some(format) => format.run(value),
+        None => format_range_value(value),
+    };
+    let percent = move |value: f64| {
+        if max > min {
+            ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        }
+    };
+
+    let on_low = move |ev: ev::Event| {
+        if let Ok(value) = event_target_value(&ev).parse::<f64>() {
+            low.set(value.min(high.get_untracked()));
+        }
+    };
+    let on_high = move |ev: ev::Event| {
+        if let Ok(value) = event_target_value(&ev).parse::<f64>() {
+            high.set(value.max(low.get_untracked()));
+        }
+    };
+
+    // When both thumbs meet, keep the one that can still move away on top
+    let low_on_top = move || low.get() > (min + max) / 2.0;
+
+    let range_class = move || {
+        merge_classes!(
+            "range",
+            color.get().as_str(),
+            size.get().as_str(),
+            "absolute inset-0 w-full pointer-events-none",
+            "[&::-webkit-slider-thumb]:pointer-events-auto [&::-moz-range-thumb]:pointer-events-auto"
+        )
+    };
+
+    let tooltip_view = move |value: RwSignal<f64>| {
+        view! {
+            <span
+                class="badge badge-sm absolute -translate-x-1/2 whitespace-nowrap"
+                style=move || {
+                    let percent = percent(value.get());
+                    format!("left: calc({percent}% + (0.5 - {percent} / 100) * 1.5rem);")
+                }
+            >
+                {move || format_value(value.get())}
+            </span>
+        }
+    };
+
+    let tick_values = ticks
+        .filter(|ticks| *ticks >= 2)
+        .map(|ticks| {
+            (0..ticks)
+                .map(|index| min + (max - min) * index as f64 / (ticks - 1) as f64)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let tick_count = tick_values.len();
+
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("w-full", class)>
+            <Show when=move || tooltip.get()>
+                <div class="relative h-6">{tooltip_view(low)} {tooltip_view(high)}</div>
+            </Show>
+            <div class="relative h-6">
+                <input
+                    type="range"
+                    class=range_class
+                    style=move || format!("--range-fill: 0; z-index: {};", if low_on_top() { 2 } else { 1 })
+                    min=min
+                    max=max
+                    step=step
+                    aria-label="Minimum value"
+                    prop:value=move || low.get()
+                    on:input=on_low
+                />
+                <input
+                    type="range"
+                    class=range_class
+                    style="--range-fill: 0; z-index: 1;"
+                    min=min
+                    max=max
+                    step=step
+                    aria-label="Maximum value"
+                    prop:value=move || high.get()
+                    on:input=on_high
+                />
+            </div>
+            <Show when=move || { tick_count >= 2 }>
+                <div class="flex justify-between px-2.5 mt-2 text-xs">
+                    {(0..tick_count).map(|_| view! { <span>"|"</span> }).collect_view()}
+                </div>
+            </Show>
+            <Show when=move || tick_count >= 2 && tick_labels.get()>
+                <div class="flex justify-between px-2.5 mt-2 text-xs">
+                    {tick_values
+                        .iter()
+                        .map(|value| view! { <span>{format_value(*value)}</span> })
+                        .collect_view()}
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// Formats a slider value with at most two decimals and no trailing zeros
+fn format_range_value(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
@@ -1,4 +1,7 @@
-use super::style::RatingSize;
+use super::{
+    super::{mask::MaskType, utils::unique_id},
+    style::RatingSize,
+};
 use crate::merge_classes;
 use leptos::{
     html::{Div, Input},
@@ -62,12 +65,20 @@ pub fn RatingItem(
     checked: Signal<bool>,
 
     /// Radio group name for the rating (all items should share the same name)
-    #[prop(optional)]
-    name: Option<&'static str>,
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
 
     /// Value for this rating level
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+
+    /// Mask shape of the item (defaults to [`MaskType::StarTwo`])
+    #[prop(optional, into)]
+    mask: MaybeProp<MaskType>,
+
+    /// Color class of the item, replacing the default `bg-orange-400`
     #[prop(optional)]
-    value: Option<&'static str>,
+    color: Option<&'static str>,
 
     /// Additional CSS classes to apply to the rating item
     #[prop(optional, into)]
@@ -77,14 +88,24 @@ pub fn RatingItem(
     #[prop(optional)]
     node_ref: NodeRef<Input>,
 ) -> impl IntoView {
+    let color = color.unwrap_or("bg-orange-400");
+
     view! {
         <input
             node_ref=node_ref
             type="radio"
-            name=name
-            value=value
+            name=move || name.get()
+            value=move || value.get()
             checked=checked
-            class=move || merge_classes!("mask", "mask-star-2", "bg-orange-400", class)
+            prop:checked=checked
+            class=move || {
+                merge_classes!(
+                    "mask",
+                    mask.get().unwrap_or(MaskType::StarTwo).as_str(),
+                    color,
+                    class
+                )
+            }
         />
     }
 }
@@ -98,9 +119,13 @@ pub fn RatingItem(
 /// - `node_ref` - References the hidden `<input>` element ([HTMLInputElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement))
 #[component]
 pub fn RatingHidden(
+    /// Whether the rating is cleared
+    #[prop(optional, into)]
+    checked: Signal<bool>,
+
     /// Radio group name that matches the rating items
-    #[prop(optional)]
-    name: Option<&'static str>,
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
 
     /// Additional CSS classes to apply to the hidden input
     #[prop(optional, into)]
@@ -114,8 +139,155 @@ pub fn RatingHidden(
         <input
             node_ref=node_ref
             type="radio"
-            name=name
+            name=move || name.get()
+            checked=checked
+            prop:checked=checked
             class=move || merge_classes!("rating-hidden", class)
         />
     }
 }
+
+/// # Rating Input Component
+///
+/// A managed star rating bound to a `value` signal. Generates the radio items with a
+/// unique group name, so no manual `name`/`checked` wiring is needed. Supports half
+/// stars, an optional clear (zero) option and a read-only display mode.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("rating rating-half rating-hidden rating-xs rating-sm rating-md rating-lg rating-xl mask mask-star-2 mask-half-1 mask-half-2 bg-orange-400");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the rating `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn RatingInput(
+    /// Current rating, from `0.0` (no rating) up to `max`
+    value: RwSignal<f32>,
+
+    /// Number of stars (defaults to 5)
+    #[prop(optional)]
+    max: Option<usize>,
+
+    /// Allow selecting half stars
+    #[prop(optional, into)]
+    half: Signal<bool>,
+
+    /// Include a hidden option that clears the rating back to zero
+    #[prop(optional, into)]
+    clearable: Signal<bool>,
+
+    /// Display the rating without allowing it to be changed
+    #[prop(optional, into)]
+    read_only: Signal<bool>,
+
+    /// Mask shape of each item (defaults to [`MaskType::StarTwo`])
+    #[prop(optional, into)]
+    mask: MaybeProp<MaskType>,
+
+    /// Size of the rating display
+    #[prop(optional, into)]
+    size: Signal<RatingSize>,
+
+    /// Additional CSS classes to apply to each item, replacing the default color
+    #[prop(optional, into)]
+    item_class: Option<&'static str>,
+
+    /// Additional CSS classes to apply to the rating container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the rating `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let name = unique_id("rating");
+    let max = max.unwrap_or(5);
+    let item_class = item_class.unwrap_or("bg-orange-400");
+
+    let items = move || {
+        let half = half.get();
+        let read_only = read_only.get();
+        let mask = mask.get().unwrap_or(MaskType::StarTwo);
+        let count = if half { max * 2 } else { max };
+        let selected = move || snap_rating(value.get(), half);
+
+        let clear = (clearable.get() && !read_only).then(|| {
+            view! {
+                <RatingHidden
+                    name=name.clone()
+                    checked=Signal::derive(move || selected() == 0.0)
+                    attr:value="0"
+                    attr:aria-label="clear rating"
+                    on:change=move |_| value.set(0.0)
+                />
+            }
+        });
+
+        let stars = (1..=count)
+            .map(|index| {
+                let item_value = if half {
+                    index as f32 / 2.0
+                } else {
+                    index as f32
+                };
+                let half_class = match (half, index % 2) {
+                    (false, _) => "",
+                    (true, 1) => "mask-half-1",
+                    (true, _) => "mask-half-2",
+                };
+                let label = format!("{item_value} star");
+
+                if read_only {
+                    view! {
+                        <div
+                            class=merge_classes!("mask", mask.as_str(), half_class, item_class)
+                            aria-label=label
+                            aria-current=move || (selected() == item_value).to_string()
+                        />
+                    }
+                    .into_any()
+                } else {
+                    view! {
+                        <RatingItem
+                            name=name.clone()
+                            value=item_value.to_string()
+                            checked=Signal::derive(move || selected() == item_value)
+                            mask=mask.clone()
+                            color=item_class
+                            class=half_class
+                            attr:aria-label=label
+                            on:change=move |_| value.set(item_value)
+                        />
+                    }
+                    .into_any()
+                }
+            })
+            .collect_view();
+
+        (clear, stars)
+    };
+
+    view! {
+        <div
+            node_ref=node_ref
+            role=move || read_only.get().then_some("img")
+            aria-label=move || {
+                read_only.get().then(|| format!("{} out of {max} stars", value.get()))
+            }
+            class=move || merge_classes!("rating", size.get().as_str(), class)
+            class:rating-half=half
+        >
+            {items}
+        </div>
+    }
+}
+
+/// Rounds a rating to the nearest selectable step (whole or half stars)
+fn snap_rating(value: f32, half: bool) -> f32 {
+    if half {
+        (value * 2.0).round() / 2.0
+    } else {
+        value.round()
+    }
+}
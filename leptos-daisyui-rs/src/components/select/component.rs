@@ -1,8 +1,11 @@
 use super::style::{SelectColor, SelectSize, SelectStyle};
 use crate::merge_classes;
 use leptos::{
+    ev,
     html::{Option_, Select as HtmlSelect},
     prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{HtmlOptionElement, HtmlSelectElement},
 };
 
 /// # Select Component
@@ -92,3 +95,259 @@ pub fn SelectOption(
         </option>
     }
 }
+
+/// # Typed Select Component
+///
+/// A data-driven daisyUI select bound to typed values. Options come from `options` and
+/// are rendered with `label`; `key` identifies each option and becomes its `value`.
+/// Options can be grouped into `<optgroup>`s and individually disabled.
+///
+/// Binding a `RwSignal<Option<T>>` gives a single select; binding a `RwSignal<Vec<T>>`
+/// renders a `multiple` select.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("select select-ghost select-primary select-secondary select-accent select-info select-success select-warning select-error select-xs select-sm select-md select-lg select-xl");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the top select element ([HTMLSelectElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLSelectElement))
+#[component]
+pub fn TypedSelect<T>(
+    /// Options to choose from
+    #[prop(into)]
+    options: Signal<Vec<T>>,
+
+    /// Selected value(s)
+    #[prop(into)]
+    selected: SelectBinding<T>,
+
+    /// Display label of an option
+    #[prop(into)]
+    label: Callback<T, String>,
+
+    /// Unique key of an option
+    #[prop(into)]
+    key: Callback<T, String>,
+
+    /// Option group of an option; options without a group are not wrapped
+    #[prop(optional, into)]
+    group: Option<Callback<T, Option<String>>>,
+
+    /// Whether an option is disabled
+    #[prop(optional, into)]
+    option_disabled: Option<Callback<T, bool>>,
+
+    /// Disabled placeholder option shown while nothing is selected
+    #[prop(optional, into)]
+    placeholder: MaybeProp<String>,
+
+    /// Style variant of the select
+    #[prop(optional, into)]
+    style: Signal<SelectStyle>,
+
+    /// Color scheme of the select
+    #[prop(optional, into)]
+    color: Signal<SelectColor>,
+
+    /// Size of the select
+    #[prop(optional, into)]
+    size: Signal<SelectSize>,
+
+    /// Whether the select is disabled
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the select element
+    #[prop(optional)]
+    node_ref: NodeRef<HtmlSelect>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let selected_keys = Memo::new(move |_| match selected {
+        SelectBinding::Single(value) => value
+            .get()
+            .map(|value| key.run(value))
+            .into_iter()
+            .collect(),
+        SelectBinding::Multiple(values) => values
+            .get()
+            .into_iter()
+            .map(|value| key.run(value))
+            .collect::<Vec<_>>(),
+    });
+
+    let on_change = move |ev: ev::Event| {
+        let Some(select) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlSelectElement>().ok())
+        else {
+            return;
+        };
+
+        let chosen = (0..select.length())
+            .filter_map(|index| select.item(index))
+            .filter_map(|item| item.dyn_into::<HtmlOptionElement>().ok())
+            .filter(|option| option.selected())
+            .map(|option| option.value())
+            .collect::<Vec<_>>();
+
+        let values = options.with_untracked(|options| {
+            options
+                .iter()
+                .filter(|option| chosen.contains(&key.run((*option).clone())))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+
+        match selected {
+            SelectBinding::Single(value) => value.set(values.into_iter().next()),
+            SelectBinding::Multiple(selected) => selected.set(values),
+        }
+    };
+
+    let option_view = move |option: T| {
+        let option_key = key.run(option.clone());
+        let is_disabled = option_disabled.is_some_and(|disabled| disabled.run(option.clone()));
+        let is_selected = {
+            let option_key = option_key.clone();
+            move || selected_keys.with(|keys| keys.contains(&option_key))
+        };
+
+        view! {
+            <option value=option_key disabled=is_disabled prop:selected=is_selected>
+                {label.run(option)}
+            </option>
+        }
+    };
+
+    view! {
+        <select
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "select",
+                    style.get().as_str(),
+                    color.get().as_str(),
+                    size.get().as_str(),
+                    class
+                )
+            }
+            multiple=selected.is_multiple()
+            disabled=disabled
+            on:change=on_change
+        >
+            {move || {
+                placeholder
+                    .get()
+                    .map(|placeholder| {
+                        view! {
+                            <option
+                                value=""
+                                disabled
+                                prop:selected=move || selected_keys.with(Vec::is_empty)
+                            >
+                                {placeholder}
+                            </option>
+                        }
+                    })
+            }}
+            {move || {
+                let options = options.get();
+                let groups = match group {
+                    Some(group) => group_options(options, |option| group.run(option.clone())),
+                    None => vec![(None, options)],
+                };
+
+                groups
+                    .into_iter()
+                    .map(|(name, items)| {
+                        let items = items.into_iter().map(option_view).collect_view();
+                        match name {
+                            Some(name) => view! { <optgroup label=name>{items}</optgroup> }.into_any(),
+                            None => items.into_any(),
+                        }
+                    })
+                    .collect_view()
+            }}
+        </select>
+    }
+}
+
+/// The value(s) bound to a [`TypedSelect`]
+pub enum SelectBinding<T: Send + Sync + 'static> {
+    /// A single optional selection
+    Single(RwSignal<Option<T>>),
+
+    /// Any number of selections, rendering a `multiple` select
+    Multiple(RwSignal<Vec<T>>),
+}
+
+impl<T: Send + Sync + 'static> SelectBinding<T> {
+    /// Whether this binding selects several values
+    pub fn is_multiple(&self) -> bool {
+        matches!(self, SelectBinding::Multiple(_))
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for SelectBinding<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for SelectBinding<T> {}
+
+impl<T: Send + Sync + 'static> From<RwSignal<Option<T>>> for SelectBinding<T> {
+    fn from(value: RwSignal<Option<T>>) -> Self {
+        SelectBinding::Single(value)
+    }
+}
+
+impl<T: Send + Sync + 'static> From<RwSignal<Vec<T>>> for SelectBinding<T> {
+    fn from(values: RwSignal<Vec<T>>) -> Self {
+        SelectBinding::Multiple(values)
+    }
+}
+
+/// Groups options by name in order of first appearance; ungrouped options share one run
+pub(crate) fn group_options<T>(
+    options: Vec<T>,
+    group: impl Fn(&T) -> Option<String>,
+) -> Vec<(Option<String>, Vec<T>)> {
+    let mut groups: Vec<(Option<String>, Vec<T>)> = Vec::new();
+    for option in options {
+        let name = group(&option);
+        match groups.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, items)) => items.push(option),
+            None => groups.push((name, vec![option])),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_options_keeps_first_appearance_order() {
+        let groups = group_options(vec!["a1", "b1", "x", "a2"], |option| {
+            (option.len() == 2).then(|| option[..1].to_string())
+        });
+
+        assert_eq!(
+            groups,
+            vec![
+                (Some("a".to_string()), vec!["a1", "a2"]),
+                (Some("b".to_string()), vec!["b1"]),
+                (None, vec!["x"]),
+            ]
+        );
+    }
+}
@@ -1,5 +1,12 @@
+use super::{
+    super::table::{TableCell, TableRow, TableSize},
+    style::{SkeletonPreset, SkeletonSize},
+};
 use crate::merge_classes;
-use leptos::{html::Div, prelude::*};
+use leptos::{
+    html::Div, prelude::*, reactive::computed::suspense::SuspenseContext, web_sys::js_sys,
+};
+use std::time::Duration;
 
 /// # Skeleton Component
 ///
@@ -24,11 +31,392 @@ pub fn Skeleton(
     node_ref: NodeRef<Div>,
 
     /// Optional child content (typically empty for pure skeleton effect)
-    children: Children,
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
     view! {
         <div node_ref=node_ref class=move || merge_classes!("skeleton", class)>
-            {children()}
+            {children.map(|children| children())}
+        </div>
+    }
+}
+
+/// # Skeleton Text Component
+///
+/// A paragraph placeholder made of skeleton lines with varying widths.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("skeleton flex flex-col gap-2 h-4 w-full w-11/12 w-5/6 w-2/3");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wrapping `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn SkeletonText(
+    /// Number of lines; defaults to 3
+    #[prop(optional, into)]
+    lines: MaybeProp<usize>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wrapping `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("flex flex-col gap-2", class)>
+            {move || {
+                let lines = lines.get().unwrap_or(3);
+                (0..lines)
+                    .map(|index| {
+                        view! {
+                            <div class=merge_classes!(
+                                "skeleton", "h-4", text_line_width(index, lines)
+                            )></div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+/// # Skeleton Avatar Component
+///
+/// A circular (or rounded square) skeleton in avatar sizes.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("skeleton shrink-0 rounded-full rounded-box size-8 size-12 size-16 size-20 size-24");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the skeleton `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn SkeletonAvatar(
+    /// Size of the avatar
+    #[prop(optional, into)]
+    size: Signal<SkeletonSize>,
+
+    /// Whether to use a rounded square instead of a circle
+    #[prop(optional, into)]
+    square: Signal<bool>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the skeleton `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    view! {
+        <div
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "skeleton shrink-0",
+                    size.get().as_str(),
+                    if square.get() { "rounded-box" } else { "rounded-full" },
+                    class
+                )
+            }
+        ></div>
+    }
+}
+
+/// # Skeleton Card Component
+///
+/// A card placeholder with an image block, a title line and text lines.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("skeleton flex flex-col gap-4 gap-2 h-32 h-4 w-full w-28 w-11/12 w-5/6 w-2/3");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wrapping `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn SkeletonCard(
+    /// Number of text lines below the title; defaults to 2
+    #[prop(optional, into)]
+    lines: MaybeProp<usize>,
+
+    /// Whether to leave out the image block
+    #[prop(optional, into)]
+    hide_image: Signal<bool>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wrapping `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("flex flex-col gap-4", class)>
+            {move || (!hide_image.get()).then(|| view! { <div class="skeleton h-32 w-full"></div> })}
+            <div class="skeleton h-4 w-28"></div>
+            <SkeletonText lines=Signal::derive(move || lines.get().or(Some(2))) />
+        </div>
+    }
+}
+
+/// # Skeleton Table Rows Component
+///
+/// Placeholder rows to put in a [`TableBody`](crate::components::TableBody) while its
+/// data loads. Line heights follow the table size.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("skeleton h-3 h-4 h-5 h-6 w-full");
+/// ```
+#[component]
+pub fn SkeletonTableRows(
+    /// Number of rows; defaults to 5
+    #[prop(optional, into)]
+    rows: MaybeProp<usize>,
+
+    /// Number of columns; defaults to 3
+    #[prop(optional, into)]
+    columns: MaybeProp<usize>,
+
+    /// Size of the surrounding table
+    #[prop(optional, into)]
+    size: Signal<TableSize>,
+
+    /// Additional CSS classes for each skeleton line
+    #[prop(optional, into)]
+    class: &'static str,
+) -> impl IntoView {
+    move || {
+        let columns = columns.get().unwrap_or(3);
+        let height = table_line_height(&size.get());
+
+        (0..rows.get().unwrap_or(5))
+            .map(|_| {
+                view! {
+                    <TableRow>
+                        {(0..columns)
+                            .map(|_| {
+                                view! {
+                                    <TableCell>
+                                        <div class=merge_classes!(
+                                            "skeleton w-full", height, class
+                                        )></div>
+                                    </TableCell>
+                                }
+                            })
+                            .collect_view()}
+                    </TableRow>
+                }
+            })
+            .collect_view()
+    }
+}
+
+/// # Skeleton Suspense Component
+///
+/// A [`Suspense`] that shows a skeleton preset while resources load. Once shown, the
+/// skeleton stays for at least `min_duration` so fast responses don't flicker.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("skeleton hidden");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wrapping `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn SkeletonSuspense(
+    /// Skeleton shown while loading
+    #[prop(optional, into)]
+    preset: Signal<SkeletonPreset>,
+
+    /// Minimum time the skeleton stays visible; defaults to 400ms
+    #[prop(optional, into)]
+    min_duration: Option<Duration>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wrapping `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+
+    /// Content reading the resources
+    children: Children,
+) -> impl IntoView {
+    let min_duration = min_duration.unwrap_or(Duration::from_millis(400));
+    let held = RwSignal::new(false);
+
+    view! {
+        <div node_ref=node_ref class=class>
+            {move || held.get().then(|| preset_view(preset.get()))}
+            <div class:hidden=held>
+                <Suspense fallback=move || preset_view(preset.get_untracked())>
+                    // Suspense provides its context only after building its children, so the
+                    // hold is set up lazily, when they are rendered inside its owner
+                    {move || hold_while_pending(held, min_duration)}
+                    {children()}
+                </Suspense>
+            </div>
         </div>
     }
 }
+
+/// Renders a skeleton preset
+fn preset_view(preset: SkeletonPreset) -> AnyView {
+    match preset {
+        SkeletonPreset::Text(lines) => view! { <SkeletonText lines=lines /> }.into_any(),
+        SkeletonPreset::Avatar => view! { <SkeletonAvatar /> }.into_any(),
+        SkeletonPreset::Card => view! { <SkeletonCard /> }.into_any(),
+    }
+}
+
+/// Keeps `held` set while the surrounding suspense is pending, and for at least
+/// `min_duration` after it started
+fn hold_while_pending(held: RwSignal<bool>, min_duration: Duration) {
+    let Some(suspense) = use_context::<SuspenseContext>() else {
+        return;
+    };
+    let hold = StoredValue::new(Hold::default());
+
+    Effect::new(move |_| {
+        let pending = suspense.tasks.with(|tasks| !tasks.is_empty());
+        let step = hold
+            .try_update_value(|hold| hold.step(pending, js_sys::Date::now(), min_duration))
+            .unwrap_or(HoldStep::Keep);
+
+        match step {
+            HoldStep::Show => held.set(true),
+            HoldStep::Hide => held.set(false),
+            HoldStep::HideAfter(remaining) => set_timeout(
+                move || {
+                    if hold.try_with_value(Hold::is_idle) == Some(true) {
+                        let _ = held.try_set(false);
+                    }
+                },
+                remaining,
+            ),
+            HoldStep::Keep => {}
+        }
+    });
+}
+
+/// When the skeleton of a [`SkeletonSuspense`] appeared, if it is showing
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Hold {
+    shown_at: Option<f64>,
+}
+
+/// What to do with the skeleton after a change of the pending state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HoldStep {
+    Show,
+    Hide,
+    HideAfter(Duration),
+    Keep,
+}
+
+impl Hold {
+    /// Records the pending state at `now`, in milliseconds
+    fn step(&mut self, pending: bool, now: f64, min_duration: Duration) -> HoldStep {
+        match (pending, self.shown_at) {
+            (true, None) => {
+                self.shown_at = Some(now);
+                HoldStep::Show
+            }
+            (false, Some(started)) => {
+                self.shown_at = None;
+                remaining_hold(started, now, min_duration)
+                    .map_or(HoldStep::Hide, HoldStep::HideAfter)
+            }
+            _ => HoldStep::Keep,
+        }
+    }
+
+    /// Whether nothing has been pending since the skeleton was last released
+    fn is_idle(&self) -> bool {
+        self.shown_at.is_none()
+    }
+}
+
+/// Time the skeleton must still be shown, given when it appeared and the current time
+/// in milliseconds
+fn remaining_hold(started: f64, now: f64, min_duration: Duration) -> Option<Duration> {
+    let remaining = min_duration.as_secs_f64() * 1000.0 - (now - started);
+    (remaining > 0.0).then(|| Duration::from_millis(remaining.ceil() as u64))
+}
+
+/// Width class of a [`SkeletonText`] line; the last of several lines is shorter
+pub(crate) fn text_line_width(index: usize, lines: usize) -> &'static str {
+    const WIDTHS: [&str; 3] = ["w-full", "w-11/12", "w-5/6"];
+
+    if lines > 1 && index == lines - 1 {
+        "w-2/3"
+    } else {
+        WIDTHS[index % WIDTHS.len()]
+    }
+}
+
+/// Height class of [`SkeletonTableRows`] lines for a table size
+fn table_line_height(size: &TableSize) -> &'static str {
+    match size {
+        TableSize::Xs => "h-3",
+        TableSize::Sm | TableSize::Md => "h-4",
+        TableSize::Lg => "h-5",
+        TableSize::Xl => "h-6",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varies_text_line_widths() {
+        assert_eq!(text_line_width(0, 1), "w-full");
+        let widths = (0..4)
+            .map(|index| text_line_width(index, 4))
+            .collect::<Vec<_>>();
+        assert_eq!(widths, vec!["w-full", "w-11/12", "w-5/6", "w-2/3"]);
+    }
+
+    #[test]
+    fn holds_for_minimum_duration() {
+        let min = Duration::from_millis(400);
+        assert_eq!(
+            remaining_hold(1000.0, 1100.0, min),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(remaining_hold(1000.0, 1400.0, min), None);
+        assert_eq!(remaining_hold(1000.0, 2000.0, min), None);
+    }
+
+    #[test]
+    fn holds_skeleton_through_fast_loads() {
+        let min = Duration::from_millis(400);
+        let mut hold = Hold::default();
+
+        assert_eq!(hold.step(false, 0.0, min), HoldStep::Keep);
+        assert_eq!(hold.step(true, 1000.0, min), HoldStep::Show);
+        assert_eq!(hold.step(true, 1050.0, min), HoldStep::Keep);
+        assert_eq!(
+            hold.step(false, 1100.0, min),
+            HoldStep::HideAfter(Duration::from_millis(300))
+        );
+        assert!(hold.is_idle());
+
+        // a reload before the timeout fires keeps the skeleton up
+        assert_eq!(hold.step(true, 1200.0, min), HoldStep::Show);
+        assert!(!hold.is_idle());
+        assert_eq!(hold.step(false, 1700.0, min), HoldStep::Hide);
+        assert!(hold.is_idle());
+    }
+}
@@ -0,0 +1,55 @@
+/// # Skeleton Size Variants
+///
+/// Size enum for square skeleton presets like [`SkeletonAvatar`](super::SkeletonAvatar).
+#[derive(Clone, Debug, Default)]
+pub enum SkeletonSize {
+    /// Extra small size (2rem)
+    Xs,
+
+    /// Small size (3rem)
+    Sm,
+
+    /// Medium size (4rem, default)
+    #[default]
+    Md,
+
+    /// Large size (5rem)
+    Lg,
+
+    /// Extra large size (6rem)
+    Xl,
+}
+
+impl SkeletonSize {
+    /// CSS class string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkeletonSize::Xs => "size-8",
+            SkeletonSize::Sm => "size-12",
+            SkeletonSize::Md => "size-16",
+            SkeletonSize::Lg => "size-20",
+            SkeletonSize::Xl => "size-24",
+        }
+    }
+}
+
+/// # Skeleton Presets
+///
+/// Placeholder shown by [`SkeletonSuspense`](super::SkeletonSuspense) while loading.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SkeletonPreset {
+    /// Paragraph with the given number of lines
+    Text(usize),
+
+    /// Circular avatar
+    Avatar,
+
+    /// Card with image, title and text lines
+    Card,
+}
+
+impl Default for SkeletonPreset {
+    fn default() -> Self {
+        SkeletonPreset::Text(3)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::merge_classes;
-use leptos::{html::Div, prelude::*};
+use leptos::{html::Div, prelude::*, web_sys::js_sys};
+use std::time::Duration;
 
 /// # Stats Component
 ///
@@ -103,8 +104,23 @@ pub fn StatValue(
 }
 
 /// Description component providing additional context for a statistic.
+///
+/// When both `current` and `previous` are set, the description starts with a trend
+/// arrow and the relative change, colored with `text-success` or `text-error`.
 #[component]
 pub fn StatDesc(
+    /// Current value used to compute the trend
+    #[prop(optional, into)]
+    current: MaybeProp<f64>,
+
+    /// Previous value used to compute the trend
+    #[prop(optional, into)]
+    previous: MaybeProp<f64>,
+
+    /// Whether a decrease is good news (e.g. bounce rate), swapping the trend colors
+    #[prop(optional, into)]
+    invert_trend: Signal<bool>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -114,11 +130,42 @@ pub fn StatDesc(
     node_ref: NodeRef<Div>,
 
     /// Description content
-    children: Children,
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
+    let trend = move || Some((current.get()?, previous.get()?));
+
     view! {
-        <div node_ref=node_ref class=move || merge_classes!("stat-desc", class)>
-            {children()}
+        <div
+            node_ref=node_ref
+            class=move || {
+                merge_classes!(
+                    "stat-desc",
+                    trend()
+                        .map(|(current, previous)| {
+                            StatTrend::between(current, previous).color_class(invert_trend.get())
+                        })
+                        .unwrap_or_default(),
+                    class
+                )
+            }
+        >
+            {move || {
+                trend()
+                    .map(|(current, previous)| {
+                        let arrow = StatTrend::between(current, previous).arrow();
+                        match relative_change(current, previous) {
+                            Some(change) => {
+                                format!(
+                                    "{arrow} {} ",
+                                    format_stat_number(change.abs(), &StatFormat::Percent, Some(0)),
+                                )
+                            }
+                            None => format!("{arrow} "),
+                        }
+                    })
+            }}
+            {children.map(|children| children())}
         </div>
     }
 }
@@ -164,3 +211,375 @@ pub fn StatActions(
         </div>
     }
 }
+
+/// # Stat Number Component
+///
+/// A `stat-value` that formats a numeric signal and tweens from the old to the new value
+/// with `requestAnimationFrame` whenever it changes.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("stat-value stat-desc text-success text-error tabular-nums");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the div element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn StatNumber(
+    /// Value to display
+    #[prop(into)]
+    value: Signal<f64>,
+
+    /// Number format of the value
+    #[prop(optional, into)]
+    format: Signal<StatFormat>,
+
+    /// Number of decimals, overriding the format's default
+    #[prop(optional, into)]
+    decimals: Option<usize>,
+
+    /// Length of the tween; defaults to 600ms, zero disables animation
+    #[prop(optional, into)]
+    duration: Option<Duration>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the div element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let duration = duration.unwrap_or(Duration::from_millis(600));
+    let displayed = RwSignal::new(value.get_untracked());
+    let generation = StoredValue::new(0u64);
+
+    Effect::new(move |_| {
+        let target = value.get();
+        let from = displayed.get_untracked();
+        generation.update_value(|n| *n = n.wrapping_add(1));
+
+        if duration.is_zero() || from == target || !from.is_finite() {
+            displayed.set(target);
+            return;
+        }
+
+        tween_frame(Tween {
+            from,
+            to: target,
+            start: js_sys::Date::now(),
+            duration: duration.as_secs_f64() * 1000.0,
+            id: generation.get_value(),
+            generation,
+            displayed,
+        });
+    });
+
+    view! {
+        <div
+            node_ref=node_ref
+            class=move || merge_classes!("stat-value", "tabular-nums", class)
+        >
+            {move || format.with(|format| format_stat_number(displayed.get(), format, decimals))}
+        </div>
+    }
+}
+
+/// A running [`StatNumber`] animation
+#[derive(Clone, Copy)]
+struct Tween {
+    /// Value at the start of the animation
+    from: f64,
+
+    /// Value at the end of the animation
+    to: f64,
+
+    /// Start time in milliseconds since the Unix epoch
+    start: f64,
+
+    /// Length of the animation in milliseconds
+    duration: f64,
+
+    /// Generation this animation belongs to
+    id: u64,
+
+    /// Latest generation; older animations stop when it changes
+    generation: StoredValue<u64>,
+
+    /// Value currently displayed
+    displayed: RwSignal<f64>,
+}
+
+/// Advances a tween by one animation frame
+fn tween_frame(tween: Tween) {
+    request_animation_frame(move || {
+        if tween.generation.try_get_value() != Some(tween.id) {
+            return;
+        }
+
+        let progress = ((js_sys::Date::now() - tween.start) / tween.duration).clamp(0.0, 1.0);
+        let value = tween.from + (tween.to - tween.from) * ease_out_cubic(progress);
+        if tween.displayed.try_set(value).is_some() {
+            return;
+        }
+
+        if progress < 1.0 {
+            tween_frame(tween);
+        }
+    });
+}
+
+/// Cubic ease-out curve for `progress` in `0.0..=1.0`
+fn ease_out_cubic(progress: f64) -> f64 {
+    1.0 - (1.0 - progress).powi(3)
+}
+
+/// Number format of a [`StatNumber`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum StatFormat {
+    /// Thousands separators, no decimals by default (`12,345`)
+    #[default]
+    Number,
+
+    /// Currency symbol prefix and two decimals by default (`$12,345.00`)
+    Currency(&'static str),
+
+    /// A ratio shown as percent, one decimal by default (`0.125` as `12.5%`)
+    Percent,
+
+    /// Compact notation with K, M, B and T suffixes (`1.2K`)
+    Compact,
+}
+
+impl StatFormat {
+    /// Default number of decimals
+    pub fn default_decimals(&self) -> usize {
+        match self {
+            StatFormat::Number => 0,
+            StatFormat::Currency(_) => 2,
+            StatFormat::Percent | StatFormat::Compact => 1,
+        }
+    }
+}
+
+/// Formats `value` for display in a stat
+pub(crate) fn format_stat_number(value: f64, format: &StatFormat, decimals: Option<usize>) -> String {
+    let decimals = decimals.unwrap_or_else(|| format.default_decimals());
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    match format {
+        StatFormat::Number => group_thousands(value, decimals),
+        StatFormat::Currency(symbol) => {
+            let amount = group_thousands(value.abs(), decimals);
+            if value < 0.0 && amount.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+                format!("-{symbol}{amount}")
+            } else {
+                format!("{symbol}{amount}")
+            }
+        }
+        StatFormat::Percent => format!("{}%", group_thousands(value * 100.0, decimals)),
+        StatFormat::Compact => format_compact(value, decimals),
+    }
+}
+
+/// Formats `value` with `decimals` decimals and comma thousands separators
+fn group_thousands(value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.decimals$}", value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::with_capacity(formatted.len() + integer.len() / 3 + 1);
+    if value < 0.0 && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        grouped.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
+/// Formats `value` in compact notation, dropping trailing zero decimals
+fn format_compact(value: f64, decimals: usize) -> String {
+    const SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+
+    let rounded = |scaled: f64, unit: usize| {
+        let factor = if unit == 0 {
+            1.0
+        } else {
+            10f64.powi(decimals as i32)
+        };
+        (scaled * factor).round() / factor
+    };
+
+    let mut scaled = value.abs();
+    let mut unit = 0;
+    while unit < SUFFIXES.len() - 1 && rounded(scaled, unit) >= 1000.0 {
+        scaled /= 1000.0;
+        unit += 1;
+    }
+
+    let decimals = if unit == 0 { 0 } else { decimals };
+    let mut number = format!("{scaled:.decimals$}");
+    if number.contains('.') {
+        number = number
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+
+    let sign = if value < 0.0 && number != "0" {
+        "-"
+    } else {
+        ""
+    };
+    format!("{sign}{number}{}", SUFFIXES[unit])
+}
+
+/// Direction of change between two stat values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatTrend {
+    /// The value increased
+    Up,
+
+    /// The value decreased
+    Down,
+
+    /// The value did not change
+    Flat,
+}
+
+impl StatTrend {
+    /// Trend from `previous` to `current`
+    pub fn between(current: f64, previous: f64) -> Self {
+        if current > previous {
+            StatTrend::Up
+        } else if current < previous {
+            StatTrend::Down
+        } else {
+            StatTrend::Flat
+        }
+    }
+
+    /// Arrow shown in front of the description
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            StatTrend::Up => "↗︎",
+            StatTrend::Down => "↘︎",
+            StatTrend::Flat => "→",
+        }
+    }
+
+    /// Text color class; `inverted` treats a decrease as good news
+    pub fn color_class(&self, inverted: bool) -> &'static str {
+        match (self, inverted) {
+            (StatTrend::Up, false) | (StatTrend::Down, true) => "text-success",
+            (StatTrend::Down, false) | (StatTrend::Up, true) => "text-error",
+            (StatTrend::Flat, _) => "",
+        }
+    }
+}
+
+/// Change from `previous` to `current` as a ratio of `previous`
+fn relative_change(current: f64, previous: f64) -> Option<f64> {
+    (previous != 0.0).then(|| (current - previous) / previous.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_numbers_with_thousands_separators() {
+        assert_eq!(format_stat_number(0.0, &StatFormat::Number, None), "0");
+        assert_eq!(format_stat_number(999.4, &StatFormat::Number, None), "999");
+        assert_eq!(
+            format_stat_number(1234567.0, &StatFormat::Number, None),
+            "1,234,567"
+        );
+        assert_eq!(
+            format_stat_number(-89400.0, &StatFormat::Number, None),
+            "-89,400"
+        );
+        assert_eq!(
+            format_stat_number(1234.5678, &StatFormat::Number, Some(2)),
+            "1,234.57"
+        );
+        assert_eq!(format_stat_number(-0.2, &StatFormat::Number, None), "0");
+    }
+
+    #[test]
+    fn formats_currency_and_percent() {
+        assert_eq!(
+            format_stat_number(1234.5, &StatFormat::Currency("$"), None),
+            "$1,234.50"
+        );
+        assert_eq!(
+            format_stat_number(-12.0, &StatFormat::Currency("€"), Some(0)),
+            "-€12"
+        );
+        assert_eq!(
+            format_stat_number(0.125, &StatFormat::Percent, None),
+            "12.5%"
+        );
+        assert_eq!(
+            format_stat_number(0.22, &StatFormat::Percent, Some(0)),
+            "22%"
+        );
+    }
+
+    #[test]
+    fn formats_compact_notation() {
+        assert_eq!(format_stat_number(999.0, &StatFormat::Compact, None), "999");
+        assert_eq!(format_stat_number(999.6, &StatFormat::Compact, None), "1K");
+        assert_eq!(format_stat_number(1000.0, &StatFormat::Compact, None), "1K");
+        assert_eq!(
+            format_stat_number(1234.0, &StatFormat::Compact, None),
+            "1.2K"
+        );
+        assert_eq!(
+            format_stat_number(25_600.0, &StatFormat::Compact, None),
+            "25.6K"
+        );
+        assert_eq!(
+            format_stat_number(999_950.0, &StatFormat::Compact, None),
+            "1M"
+        );
+        assert_eq!(
+            format_stat_number(-4_200_000.0, &StatFormat::Compact, None),
+            "-4.2M"
+        );
+        assert_eq!(
+            format_stat_number(3.0e15, &StatFormat::Compact, None),
+            "3000T"
+        );
+    }
+
+    #[test]
+    fn computes_trend_and_change() {
+        assert_eq!(StatTrend::between(5.0, 4.0), StatTrend::Up);
+        assert_eq!(StatTrend::between(3.0, 4.0), StatTrend::Down);
+        assert_eq!(StatTrend::between(4.0, 4.0), StatTrend::Flat);
+        assert_eq!(StatTrend::Down.color_class(true), "text-success");
+        assert_eq!(relative_change(122.0, 100.0), Some(0.22));
+        assert_eq!(relative_change(1.0, 0.0), None);
+    }
+
+    #[test]
+    fn eases_out() {
+        assert_eq!(ease_out_cubic(0.0), 0.0);
+        assert_eq!(ease_out_cubic(1.0), 1.0);
+        assert!(ease_out_cubic(0.5) > 0.5);
+    }
+}
@@ -1,9 +1,17 @@
-use super::style::{StepColor, StepsDirection};
+use super::{
+    super::{
+        button::{Button, ButtonColor, ButtonStyle},
+        utils::{IconSlot, icon_slot},
+    },
+    style::{StepColor, StepsDirection},
+};
 use crate::merge_classes;
 use leptos::{
-    html::{Li, Ul},
+    html::{Div, Li, Ul},
     prelude::*,
+    task::spawn_local,
 };
+use std::{future::Future, pin::Pin, sync::Arc};
 
 /// # Steps Component
 ///
@@ -12,7 +20,7 @@ use leptos::{
 ///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("steps step steps-vertical steps-horizontal step-primary step-secondary step-accent step-neutral step-info step-success step-warning step-error");
+/// @source inline("steps step step-icon steps-vertical steps-horizontal step-primary step-secondary step-accent step-neutral step-info step-success step-warning step-error");
 /// ```
 ///
 /// ## Node References
@@ -65,7 +73,15 @@ pub fn Step(
 
     /// Content for the step indicator (numbers, symbols, etc.)
     #[prop(optional, into)]
-    data_content: Option<&'static str>,
+    data_content: MaybeProp<String>,
+
+    /// Icon replacing the step indicator (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the step content (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
 
     /// Additional CSS classes to apply to the step
     #[prop(optional, into)]
@@ -86,9 +102,284 @@ pub fn Step(
                 color.get().as_str(),
                 class)
             }
-            data-content=data_content
+            data-content=move || data_content.get()
         >
+            {move || {
+                icon_start
+                    .get()
+                    .is_some()
+                    .then(|| {
+                        view! {
+                            <span class="step-icon">
+                                {icon_slot(move || icon_start.get(), || "1em")}
+                            </span>
+                        }
+                    })
+            }}
             {children()}
+            {icon_slot(move || icon_end.get(), || "1em")}
         </li>
     }
 }
+
+/// # Wizard Component
+///
+/// A multi-step flow built on daisyUI steps. Renders a [`Steps`] progress indicator
+/// for the given [`WizardStep`]s, the panel of the current step and back/skip/next
+/// navigation. Completed and current steps are colored automatically, and completed
+/// steps can be clicked to go back to them.
+///
+/// A step's validator runs when moving forward from it; navigation waits for the
+/// validator and shows its error message if it fails.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("steps step step-icon step-neutral step-primary step-secondary step-accent step-info step-success step-warning step-error steps-vertical steps-horizontal btn btn-primary btn-ghost loading loading-spinner loading-sm");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the wizard `<div>` element ([HTMLDivElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLDivElement))
+#[component]
+pub fn Wizard(
+    /// Ordered list of steps
+    steps: Vec<WizardStep>,
+
+    /// Index of the current step, starting at 0
+    #[prop(optional)]
+    current_step: RwSignal<usize>,
+
+    /// Direction of the steps indicator
+    #[prop(optional, into)]
+    direction: Signal<StepsDirection>,
+
+    /// Color of completed and current steps (defaults to [`StepColor::Primary`])
+    #[prop(optional, into)]
+    color: Signal<Option<StepColor>>,
+
+    /// Called when moving forward from the last step
+    #[prop(optional, into)]
+    on_finish: Option<Callback<()>>,
+
+    /// Additional CSS classes to apply to the wizard container
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference for the wizard `<div>` element
+    #[prop(optional)]
+    node_ref: NodeRef<Div>,
+) -> impl IntoView {
+    let step_count = steps.len();
+    let skipped = RwSignal::new(vec![false; step_count]);
+    let validating = RwSignal::new(false);
+    let error = RwSignal::new(None::<String>);
+    let steps = StoredValue::new(steps);
+
+    let is_skipped =
+        move |index: usize| skipped.with(|skipped| skipped.get(index).copied().unwrap_or(false));
+
+    let go_to = move |index: usize| {
+        if !validating.get_untracked() {
+            error.set(None);
+            current_step.set(index);
+        }
+    };
+
+    let advance = move |from: usize, skip: bool| {
+        // Ignore a late validation result if the user navigated elsewhere meanwhile
+        if current_step.get_untracked() != from {
+            return;
+        }
+
+        skipped.update(|skipped| {
+            if let Some(skipped) = skipped.get_mut(from) {
+                *skipped = skip;
+            }
+        });
+        if from + 1 < step_count {
+            current_step.set(from + 1);
+        } else if let Some(on_finish) = on_finish {
+            on_finish.run(());
+        }
+    };
+
+    let on_next = move |_| {
+        if validating.get_untracked() {
+            return;
+        }
+
+        let index = current_step.get_untracked();
+        let validate = steps.with_value(|steps| steps.get(index)?.validate.clone());
+        let Some(validate) = validate else {
+            error.set(None);
+            advance(index, false);
+            return;
+        };
+
+        validating.set(true);
+        error.set(None);
+        spawn_local(async move {
+            let result = validate().await;
+            validating.set(false);
+            match result {
+                Ok(()) => advance(index, false),
+                Err(message) => error.set(Some(message)),
+            }
+        });
+    };
+
+    let indicators = steps.with_value(|steps| {
+        steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let completed = move || index < current_step.get();
+                let reached = move || index <= current_step.get() && !is_skipped(index);
+                let step_color = Signal::derive(move || {
+                    if reached() {
+                        color.get().unwrap_or(StepColor::Primary)
+                    } else {
+                        StepColor::Default
+                    }
+                });
+                view! {
+                    <Step
+                        color=step_color
+                        class:cursor-pointer=completed
+                        attr:role=move || completed().then_some("button")
+                        attr:tabindex=move || completed().then_some("0")
+                        attr:aria-current=move || (index == current_step.get()).then_some("step")
+                        data_content=Signal::derive(move || {
+                            if is_skipped(index) {
+                                "–".to_string()
+                            } else if completed() {
+                                "✓".to_string()
+                            } else {
+                                (index + 1).to_string()
+                            }
+                        })
+                        on:click=move |_| {
+                            if completed() {
+                                go_to(index);
+                            }
+                        }
+                        on:keydown=move |ev| {
+                            if completed() && matches!(ev.key().as_str(), "Enter" | " ") {
+                                ev.prevent_default();
+                                go_to(index);
+                            }
+                        }
+                    >
+                        {step.title.clone()}
+                    </Step>
+                }
+            })
+            .collect_view()
+    });
+
+    let is_last = move || current_step.get() + 1 >= step_count;
+    let can_skip = move || {
+        let index = current_step.get();
+        !is_last() && steps.with_value(|steps| steps.get(index).is_some_and(|step| step.skippable))
+    };
+
+    view! {
+        <div node_ref=node_ref class=move || merge_classes!("flex flex-col gap-6", class)>
+            <Steps direction=direction>{indicators}</Steps>
+            <div>
+                {move || {
+                    let index = current_step.get();
+                    steps.with_value(|steps| steps.get(index).map(|step| step.content.run()))
+                }}
+            </div>
+            {move || error.get().map(|message| view! { <p class="text-error">{message}</p> })}
+            <div class="flex justify-between gap-2">
+                <Button
+                    style=ButtonStyle::Ghost
+                    disabled=Signal::derive(move || current_step.get() == 0 || validating.get())
+                    attr:type="button"
+                    on:click=move |_| go_to(current_step.get_untracked().saturating_sub(1))
+                >
+                    "Back"
+                </Button>
+                <div class="flex gap-2">
+                    <Show when=can_skip>
+                        <Button
+                            style=ButtonStyle::Ghost
+                            disabled=validating
+                            attr:type="button"
+                            on:click=move |_| {
+                                error.set(None);
+                                advance(current_step.get_untracked(), true);
+                            }
+                        >
+                            "Skip"
+                        </Button>
+                    </Show>
+                    <Button
+                        color=ButtonColor::Primary
+                        disabled=validating
+                        attr:type="button"
+                        on:click=on_next
+                    >
+                        <Show when=move || validating.get()>
+                            <span class="loading loading-spinner loading-sm"></span>
+                        </Show>
+                        {move || if is_last() { "Finish" } else { "Next" }}
+                    </Button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Asynchronous check run before leaving a wizard step
+type StepValidator =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<(), String>>>> + Send + Sync>;
+
+/// A single step of a [`Wizard`]
+///
+/// Holds the title shown in the steps indicator, the panel content and optional
+/// validation. The content is re-rendered whenever the step becomes current, so any
+/// form state should live in signals owned outside the wizard.
+#[derive(Clone)]
+pub struct WizardStep {
+    /// Title shown in the steps indicator
+    title: String,
+
+    /// Panel content for the step
+    content: ViewFn,
+
+    /// Validation that must pass before moving past the step
+    validate: Option<StepValidator>,
+
+    /// Whether the step can be skipped without validation
+    skippable: bool,
+}
+
+impl WizardStep {
+    /// Creates a step with a title and panel content
+    pub fn new(title: impl Into<String>, content: impl Into<ViewFn>) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            validate: None,
+            skippable: false,
+        }
+    }
+
+    /// Adds a validation gate; returning `Err(message)` keeps the wizard on this step
+    pub fn validate<F, Fut>(mut self, validate: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.validate = Some(Arc::new(move || Box::pin(validate())));
+        self
+    }
+
+    /// Allows the step to be skipped without running its validation
+    pub fn skippable(mut self) -> Self {
+        self.skippable = true;
+        self
+    }
+}
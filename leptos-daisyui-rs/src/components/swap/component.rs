@@ -1,4 +1,4 @@
-use super::style::SwapRotate;
+use super::style::{SwapPreset, SwapRotate};
 use crate::merge_classes;
 use leptos::{
     html::{Div, Label},
@@ -10,9 +10,14 @@ use leptos::{
 /// Toggles between two elements using a checkbox. When active, shows the "on" content;
 /// when inactive, shows the "off" content.
 ///
+/// `active` stays in sync with the hidden checkbox, so user clicks are reflected in the
+/// signal and reported through `on_toggle`. With a `name`, the checkbox submits `value`
+/// (or `on`) in forms while active. Pass a `preset` instead of children for common icon
+/// pairs.
+///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("swap swap-on swap-off swap-indeterminate swap-active swap-rotate swap-flip");
+/// @source inline("swap swap-on swap-off swap-indeterminate swap-active swap-rotate swap-flip fill-current");
 /// ```
 ///
 /// ## Node References
@@ -24,13 +29,29 @@ pub fn Swap(
     rotate: Signal<SwapRotate>,
 
     /// Whether the swap is currently active (showing "on" content)
-    #[prop(optional, into)]
-    active: Signal<bool>,
+    #[prop(optional)]
+    active: RwSignal<bool>,
 
     /// Whether the swap is currently indeterminate (showing "indeterminate" content)
     #[prop(optional, into)]
     indeterminate: Signal<bool>,
 
+    /// Called with the new state when the user toggles the swap
+    #[prop(optional, into)]
+    on_toggle: Option<Callback<bool>>,
+
+    /// Form field name of the hidden checkbox
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+
+    /// Value submitted while active
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+
+    /// Built-in icon pair rendered when no children are given
+    #[prop(optional, into)]
+    preset: Option<SwapPreset>,
+
     /// Additional CSS classes to apply to the swap container
     #[prop(optional, into)]
     class: &'static str,
@@ -40,8 +61,47 @@ pub fn Swap(
     node_ref: NodeRef<Label>,
 
     /// Child components: [`SwapOn`] and [`SwapOff`]
-    children: Children,
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
+    let on_change = move |ev| {
+        let checked = event_target_checked(&ev);
+        active.set(checked);
+        if let Some(on_toggle) = on_toggle {
+            on_toggle.run(checked);
+        }
+    };
+
+    let content = match (children, preset) {
+        (Some(children), _) => children(),
+        (None, Some(preset)) => view! {
+            <SwapOn>
+                <svg
+                    class="fill-current"
+                    width="32"
+                    height="32"
+                    viewBox=preset.view_box()
+                    aria-hidden="true"
+                >
+                    <path d=preset.on_path() />
+                </svg>
+            </SwapOn>
+            <SwapOff>
+                <svg
+                    class="fill-current"
+                    width="32"
+                    height="32"
+                    viewBox=preset.view_box()
+                    aria-hidden="true"
+                >
+                    <path d=preset.off_path() />
+                </svg>
+            </SwapOff>
+        }
+        .into_any(),
+        (None, None) => ().into_any(),
+    };
+
     view! {
         <label
             node_ref=node_ref
@@ -51,8 +111,16 @@ pub fn Swap(
                 class)
             }
         >
-            <input type="checkbox" checked=active prop:indeterminate=indeterminate />
-            {children()}
+            <input
+                type="checkbox"
+                name=move || name.get()
+                value=move || value.get()
+                checked=move || active.get()
+                prop:checked=move || active.get()
+                prop:indeterminate=indeterminate
+                on:change=on_change
+            />
+            {content}
         </label>
     }
 }
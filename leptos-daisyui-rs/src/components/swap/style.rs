@@ -23,3 +23,59 @@ impl SwapRotate {
         }
     }
 }
+
+/// # Swap Presets
+///
+/// Built-in icon pairs for [`Swap`](super::Swap). The first icon of each name is shown
+/// while active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapPreset {
+    /// Close (active) and hamburger menu icons, best with `SwapRotate::Rotate`
+    HamburgerClose,
+
+    /// Moon (active) and sun icons for dark mode toggles
+    MoonSun,
+
+    /// Speaker (active) and muted speaker icons
+    Volume,
+}
+
+impl SwapPreset {
+    /// SVG `viewBox` of the preset icons
+    pub fn view_box(&self) -> &'static str {
+        match self {
+            SwapPreset::HamburgerClose => "0 0 512 512",
+            SwapPreset::MoonSun | SwapPreset::Volume => "0 0 24 24",
+        }
+    }
+
+    /// SVG path of the icon shown while active
+    pub fn on_path(&self) -> &'static str {
+        match self {
+            SwapPreset::HamburgerClose => {
+                "M400 145.49 366.51 112 256 222.51 145.49 112 112 145.49 222.51 256 112 366.51 145.49 400 256 289.49 366.51 400 400 366.51 289.49 256 400 145.49z"
+            }
+            SwapPreset::MoonSun => {
+                "M21.64,13a1,1,0,0,0-1.05-.14,8.05,8.05,0,0,1-3.37.73A8.15,8.15,0,0,1,9.08,5.49a8.59,8.59,0,0,1,.25-2A1,1,0,0,0,8,2.36,10.14,10.14,0,1,0,22,14.05,1,1,0,0,0,21.64,13Zm-9.5,6.69A8.14,8.14,0,0,1,7.08,5.22v.27A10.15,10.15,0,0,0,17.22,15.63a9.79,9.79,0,0,0,2.1-.22A8.11,8.11,0,0,1,12.14,19.73Z"
+            }
+            SwapPreset::Volume => {
+                "M14,3.23V5.29C16.89,6.15 19,8.83 19,12C19,15.17 16.89,17.84 14,18.7V20.77C18,19.86 21,16.28 21,12C21,7.72 18,4.14 14,3.23M16.5,12C16.5,10.23 15.5,8.71 14,7.97V16C15.5,15.29 16.5,13.76 16.5,12M3,9V15H7L12,20V4L7,9H3Z"
+            }
+        }
+    }
+
+    /// SVG path of the icon shown while inactive
+    pub fn off_path(&self) -> &'static str {
+        match self {
+            SwapPreset::HamburgerClose => {
+                "M64,384H448V341.33H64Zm0-106.67H448V234.67H64ZM64,128v42.67H448V128Z"
+            }
+            SwapPreset::MoonSun => {
+                "M5.64,17l-.71.71a1,1,0,0,0,0,1.41,1,1,0,0,0,1.41,0l.71-.71A1,1,0,0,0,5.64,17ZM5,12a1,1,0,0,0-1-1H3a1,1,0,0,0,0,2H4A1,1,0,0,0,5,12Zm7-7a1,1,0,0,0,1-1V3a1,1,0,0,0-2,0V4A1,1,0,0,0,12,5ZM5.64,7.05a1,1,0,0,0,.7.29,1,1,0,0,0,.71-.29,1,1,0,0,0,0-1.41l-.71-.71A1,1,0,0,0,4.93,6.34Zm12,.29a1,1,0,0,0,.7-.29l.71-.71a1,1,0,1,0-1.41-1.41L17,5.64a1,1,0,0,0,0,1.41A1,1,0,0,0,17.66,7.34ZM21,11H20a1,1,0,0,0,0,2h1a1,1,0,0,0,0-2Zm-9,8a1,1,0,0,0-1,1v1a1,1,0,0,0,2,0V20A1,1,0,0,0,12,19ZM18.36,17A1,1,0,0,0,17,18.36l.71.71a1,1,0,0,0,1.41,0,1,1,0,0,0,0-1.41ZM12,6.5A5.5,5.5,0,1,0,17.5,12,5.51,5.51,0,0,0,12,6.5Zm0,9A3.5,3.5,0,1,1,15.5,12,3.5,3.5,0,0,1,12,15.5Z"
+            }
+            SwapPreset::Volume => {
+                "M3,9H7L12,4V20L7,15H3V9M16.59,12L14,9.41L15.41,8L18,10.59L20.59,8L22,9.41L19.41,12L22,14.59L20.59,16L18,13.41L15.41,16L14,14.59L16.59,12Z"
+            }
+        }
+    }
+}
@@ -1,9 +1,16 @@
-use super::style::{TabPlacement, TabSize, TabVariant};
+#[cfg(feature = "router")]
+use super::super::utils::use_route_active;
+use super::{
+    super::utils::{IconSlot, RouteMatch, icon_slot},
+    style::{TabPlacement, TabSize, TabVariant},
+};
 use crate::merge_classes;
 use leptos::{
     html::{A, Div, Input},
     prelude::*,
 };
+#[cfg(feature = "router")]
+use leptos_router::components::A as RouterLink;
 
 /// # Tabs Component
 ///
@@ -12,7 +19,7 @@ use leptos::{
 ///
 /// ### Add to `input.css`
 /// ```css
-/// @source inline("tabs tab tab-active tab-disabled tabs-box tabs-border tabs-lift tabs-top tabs-bottom");
+/// @source inline("tabs tab tab-active tab-disabled tabs-box tabs-border tabs-lift tabs-top tabs-bottom gap-2");
 /// ```
 ///
 /// ## Node References
@@ -65,6 +72,10 @@ pub fn Tabs(
 /// A reactive Leptos wrapper for individual tab items with click handling
 /// and active state.
 ///
+/// With the `router` feature enabled, a tab with an `href` becomes a router link and is
+/// also active whenever the current location matches it. Such tabs only work inside a
+/// `<Router>`, as `leptos_router` panics when it cannot find one.
+///
 /// ## Node References
 /// - `node_ref` - References the anchor element ([HTMLAnchorElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement))
 #[component]
@@ -77,6 +88,22 @@ pub fn Tab(
     #[prop(optional, into)]
     disabled: Signal<bool>,
 
+    /// Optional URL the tab links to
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+
+    /// How `href` is compared with the current route (`router` feature only)
+    #[prop(optional, into)]
+    route_match: Signal<RouteMatch>,
+
+    /// Icon shown before the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_start: MaybeProp<IconSlot>,
+
+    /// Icon shown after the label (`icons` feature)
+    #[prop(optional, into)]
+    icon_end: MaybeProp<IconSlot>,
+
     /// Additional CSS classes
     #[prop(optional, into)]
     class: &'static str,
@@ -88,16 +115,49 @@ pub fn Tab(
     /// Tab label content
     children: Children,
 ) -> impl IntoView {
+    let has_icon = move || icon_start.get().is_some() || icon_end.get().is_some();
+    let content = view! {
+        {icon_slot(move || icon_start.get(), || "1em")}
+        {children()}
+        {icon_slot(move || icon_end.get(), || "1em")}
+    };
+
+    #[cfg(feature = "router")]
+    if href.get_untracked().is_some() {
+        let href = Signal::derive(move || href.get().unwrap_or_default());
+        let route_active = use_route_active(href, route_match);
+
+        return view! {
+            <RouterLink
+                href=move || href.get()
+                {..}
+                node_ref=node_ref
+                class=move || merge_classes!("tab", class)
+                class:tab-active=move || active.get() || route_active.get()
+                class:tab-disabled=disabled
+                class:gap-2=has_icon
+            >
+                {content}
+            </RouterLink>
+        }
+        .into_any();
+    }
+    #[cfg(not(feature = "router"))]
+    let _ = route_match;
+
     view! {
         <a
             node_ref=node_ref
+            href=move || href.get()
             class=move || merge_classes!("tab", class)
             class:tab-active=active
             class:tab-disabled=disabled
+            class:gap-2=has_icon
         >
-            {children()}
+            {content}
         </a>
     }
+    .into_any()
 }
 
 /// # Tab Radio Component
@@ -1,9 +1,10 @@
-use super::style::{TimelineDirection, TimelineItemPosition};
+use super::style::{TimelineConnectorColor, TimelineDirection, TimelineItemPosition};
 use crate::merge_classes;
 use leptos::{
     html::{Div, Li, Ul},
     prelude::*,
 };
+use std::collections::HashMap;
 
 /// # Timeline Component
 ///
@@ -218,3 +219,220 @@ pub fn TimelineItemEnd(
         </div>
     }
 }
+
+/// # Timeline List Component
+///
+/// Renders a timeline from a list of events. Each event gets optional start, middle and
+/// end content, and the `<hr>` connectors between events are generated automatically.
+/// Connectors leading to a completed event are colored with `connector_color`.
+///
+/// With `alternate`, every other event swaps its start and end content, and `boxed`
+/// applies the box style to the end content wherever it is placed.
+///
+/// ### Add to `input.css`
+/// ```css
+/// @source inline("timeline timeline-vertical timeline-horizontal timeline-snap-icon timeline-compact timeline-start timeline-middle timeline-end timeline-box bg-primary bg-secondary bg-accent bg-neutral bg-info bg-success bg-warning bg-error");
+/// ```
+///
+/// ## Node References
+/// - `node_ref` - References the ul element ([HTMLUListElement](https://developer.mozilla.org/en-US/docs/Web/API/HTMLUListElement))
+#[component]
+pub fn TimelineList<E>(
+    /// Events in timeline order
+    #[prop(into)]
+    events: Signal<Vec<E>>,
+
+    /// Unique key of an event
+    #[prop(into)]
+    key: Callback<E, String>,
+
+    /// Content rendered in `timeline-start`
+    #[prop(optional, into)]
+    start: Option<Callback<E, AnyView>>,
+
+    /// Content rendered in `timeline-middle`, typically an icon
+    #[prop(optional, into)]
+    middle: Option<Callback<E, AnyView>>,
+
+    /// Content rendered in `timeline-end`
+    #[prop(optional, into)]
+    end: Option<Callback<E, AnyView>>,
+
+    /// Whether an event is completed; connectors leading to it are colored
+    #[prop(optional, into)]
+    completed: Option<Callback<E, bool>>,
+
+    /// Color of connectors leading to completed events
+    #[prop(optional, into)]
+    connector_color: Signal<TimelineConnectorColor>,
+
+    /// Whether every other event swaps its start and end content
+    #[prop(optional, into)]
+    alternate: Signal<bool>,
+
+    /// Whether to apply box styling to the end content
+    #[prop(optional, into)]
+    boxed: Signal<bool>,
+
+    /// Direction of the timeline layout
+    #[prop(optional, into)]
+    direction: Signal<TimelineDirection>,
+
+    /// Whether to snap icons to timeline
+    #[prop(optional, into)]
+    snap_icon: Signal<bool>,
+
+    /// Whether to use compact spacing
+    #[prop(optional, into)]
+    compact: Signal<bool>,
+
+    /// Additional CSS classes
+    #[prop(optional, into)]
+    class: &'static str,
+
+    /// Node reference to the ul element
+    #[prop(optional)]
+    node_ref: NodeRef<Ul>,
+) -> impl IntoView
+where
+    E: Clone + Send + Sync + 'static,
+{
+    // Position and connectors of each event by key, so rows survive reordering
+    let layout = Memo::new(move |_| {
+        events.with(|events| {
+            let done = events
+                .iter()
+                .map(|event| completed.is_some_and(|completed| completed.run(event.clone())))
+                .collect::<Vec<_>>();
+
+            events
+                .iter()
+                .zip(timeline_connectors(&done))
+                .enumerate()
+                .map(|(index, (event, connectors))| (key.run(event.clone()), (index, connectors)))
+                .collect::<HashMap<_, _>>()
+        })
+    });
+
+    let connector = move |completed: bool| {
+        view! {
+            <hr class=move || {
+                if completed { connector_color.get().as_str() } else { "" }
+            } />
+        }
+    };
+
+    let row_view = move |event: E| {
+        let row_key = key.run(event.clone());
+        let position = Memo::new(move |_| {
+            layout.with(|layout| layout.get(&row_key).copied().unwrap_or_default())
+        });
+        let connectors = move || position.get().1;
+        let swapped = move || alternate.get() && position.get().0 % 2 == 1;
+        let start_content = start.map(|start| start.run(event.clone()));
+        let end_content = end.map(|end| end.run(event.clone()));
+        let middle_content = middle.map(|middle| {
+            view! { <div class="timeline-middle">{middle.run(event.clone())}</div> }
+        });
+
+        view! {
+            <li>
+                {move || connectors().before.map(connector)}
+                {start_content
+                    .map(|content| {
+                        view! {
+                            <div
+                                class=move || {
+                                    if swapped() { "timeline-end" } else { "timeline-start" }
+                                }
+                            >
+                                {content}
+                            </div>
+                        }
+                    })}
+                {middle_content}
+                {end_content
+                    .map(|content| {
+                        view! {
+                            <div
+                                class=move || {
+                                    if swapped() { "timeline-start" } else { "timeline-end" }
+                                }
+                                class:timeline-box=boxed
+                            >
+                                {content}
+                            </div>
+                        }
+                    })}
+                {move || connectors().after.map(connector)}
+            </li>
+        }
+    };
+
+    view! {
+        <Timeline
+            direction=direction
+            snap_icon=snap_icon
+            compact=compact
+            class=class
+            node_ref=node_ref
+        >
+            <For
+                each=move || events.get()
+                key=move |event| key.run(event.clone())
+                children=row_view
+            />
+        </Timeline>
+    }
+}
+
+/// Connector lines of an event in a [`TimelineList`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct TimelineConnectors {
+    /// Connector before the event, `Some(completed)` when rendered
+    pub before: Option<bool>,
+
+    /// Connector after the event, `Some(completed)` when rendered
+    pub after: Option<bool>,
+}
+
+/// Computes the connectors of each event from their completion states.
+///
+/// Both halves of the line between two events are colored when the later event is completed.
+pub(crate) fn timeline_connectors(completed: &[bool]) -> Vec<TimelineConnectors> {
+    completed
+        .iter()
+        .enumerate()
+        .map(|(index, done)| TimelineConnectors {
+            before: (index > 0).then_some(*done),
+            after: completed.get(index + 1).copied(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connectors(before: Option<bool>, after: Option<bool>) -> TimelineConnectors {
+        TimelineConnectors { before, after }
+    }
+
+    #[test]
+    fn connects_neighbors_by_completion() {
+        assert_eq!(
+            timeline_connectors(&[true, true, false]),
+            vec![
+                connectors(None, Some(true)),
+                connectors(Some(true), Some(false)),
+                connectors(Some(false), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_event_has_no_connectors() {
+        assert_eq!(timeline_connectors(&[true]), vec![connectors(None, None)]);
+        assert!(timeline_connectors(&[]).is_empty());
+    }
+}
@@ -63,3 +63,51 @@ impl TimelineItemPosition {
         matches!(self, TimelineItemPosition::Between)
     }
 }
+
+/// Color variants for completed connector lines of a `TimelineList`.
+///
+/// Connectors leading to a completed event use this background color, the others
+/// fall back to the default timeline line color.
+#[derive(Clone, Debug, Default)]
+pub enum TimelineConnectorColor {
+    /// Primary brand color (default)
+    #[default]
+    Primary,
+
+    /// Secondary brand color
+    Secondary,
+
+    /// Accent brand color
+    Accent,
+
+    /// Neutral color
+    Neutral,
+
+    /// Info color
+    Info,
+
+    /// Success color
+    Success,
+
+    /// Warning color
+    Warning,
+
+    /// Error color
+    Error,
+}
+
+impl TimelineConnectorColor {
+    /// CSS class string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineConnectorColor::Primary => "bg-primary",
+            TimelineConnectorColor::Secondary => "bg-secondary",
+            TimelineConnectorColor::Accent => "bg-accent",
+            TimelineConnectorColor::Neutral => "bg-neutral",
+            TimelineConnectorColor::Info => "bg-info",
+            TimelineConnectorColor::Success => "bg-success",
+            TimelineConnectorColor::Warning => "bg-warning",
+            TimelineConnectorColor::Error => "bg-error",
+        }
+    }
+}
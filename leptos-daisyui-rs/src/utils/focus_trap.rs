@@ -0,0 +1,116 @@
+use leptos::{
+    ev,
+    html::ElementType,
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{Element, HtmlElement, Node},
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Selector matching elements that can receive keyboard focus
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type='hidden']), select:not([disabled]), \
+    textarea:not([disabled]), iframe, summary, [contenteditable='true'], \
+    [tabindex]:not([tabindex='-1'])";
+
+/// Traps keyboard focus inside the referenced element while `active` is true
+///
+/// When the trap activates, the currently focused element is remembered and focus
+/// moves to the first focusable descendant. `Tab` and `Shift+Tab` then cycle within
+/// the element. When the trap deactivates, focus returns to the remembered element,
+/// which is usually the control that opened the overlay.
+pub fn use_focus_trap<E>(node_ref: NodeRef<E>, active: impl Into<Signal<bool>>)
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    let active = active.into();
+    let previous_focus: Rc<RefCell<Option<HtmlElement>>> = Rc::new(RefCell::new(None));
+
+    Effect::new(move |_| {
+        let Some(node) = node_ref.get() else { return };
+        let container = node.unchecked_into::<Element>();
+
+        if active.get() {
+            *previous_focus.borrow_mut() = focused_element();
+
+            // Wait for the overlay to become visible before moving focus into it
+            request_animation_frame(move || {
+                if contains_focus(&container) {
+                    return;
+                }
+
+                match focusable_elements(&container).first() {
+                    Some(first) => {
+                        let _ = first.focus();
+                    }
+                    None => {
+                        let _ = container.unchecked_ref::<HtmlElement>().focus();
+                    }
+                }
+            });
+        } else if let Some(previous) = previous_focus.borrow_mut().take() {
+            let _ = previous.focus();
+        }
+    });
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() != "Tab" || !active.get_untracked() {
+            return;
+        }
+
+        let Some(node) = node_ref.get_untracked() else {
+            return;
+        };
+        let container = node.unchecked_into::<Element>();
+
+        let focusable = focusable_elements(&container);
+        let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+            ev.prevent_default();
+            return;
+        };
+
+        let inside = contains_focus(&container);
+        let current = focused_element();
+
+        if ev.shift_key() {
+            if !inside || current.as_ref() == Some(first) {
+                ev.prevent_default();
+                let _ = last.focus();
+            }
+        } else if !inside || current.as_ref() == Some(last) {
+            ev.prevent_default();
+            let _ = first.focus();
+        }
+    });
+
+    on_cleanup(move || handle.remove());
+}
+
+/// Returns the element that currently has focus in the document
+fn focused_element() -> Option<HtmlElement> {
+    document()
+        .active_element()
+        .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+}
+
+/// Whether the focused element is `container` itself or one of its descendants
+fn contains_focus(container: &Element) -> bool {
+    document().active_element().is_some_and(|el| {
+        let node: &Node = el.as_ref();
+        container.contains(Some(node))
+    })
+}
+
+/// Collects the visible, focusable descendants of `container` in DOM order
+fn focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|el| el.get_client_rects().length() > 0)
+        .collect()
+}
@@ -0,0 +1,397 @@
+use leptos::{
+    ev, logging,
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{EventTarget, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement},
+};
+
+/// Modifier keys held during a key press
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    /// `Control` key
+    pub ctrl: bool,
+
+    /// `Alt` (`Option` on Apple platforms) key
+    pub alt: bool,
+
+    /// `Shift` key
+    pub shift: bool,
+
+    /// `Meta` (`Command` on Apple platforms) key
+    pub meta: bool,
+}
+
+impl Modifiers {
+    /// Modifiers held during a keyboard event
+    pub fn from_event(ev: &ev::KeyboardEvent) -> Self {
+        Modifiers {
+            ctrl: ev.ctrl_key(),
+            alt: ev.alt_key(),
+            shift: ev.shift_key(),
+            meta: ev.meta_key(),
+        }
+    }
+}
+
+/// A keyboard shortcut such as `Mod+Shift+K`
+///
+/// `Mod` is the platform's primary modifier: `Command` on Apple platforms and `Ctrl`
+/// elsewhere, so one definition can be displayed with [`Shortcut`](crate::components::Shortcut)
+/// and bound with [`use_hotkey`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotkey {
+    /// Whether the platform's primary modifier (`Mod`) is required
+    pub primary: bool,
+
+    /// Other required modifiers
+    pub modifiers: Modifiers,
+
+    /// Key, as reported by `KeyboardEvent.key` (single characters in lowercase)
+    pub key: String,
+}
+
+impl Hotkey {
+    /// Parses a `+`-separated shortcut like `Mod+K`, `Ctrl+Alt+Delete` or `Shift+?`
+    ///
+    /// Modifier names are case-insensitive. Returns `None` for an unknown modifier or a
+    /// missing key.
+    pub fn parse(shortcut: &str) -> Option<Self> {
+        let shortcut = shortcut.trim();
+        let (modifiers, key) = match shortcut.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None => shortcut.rsplit_once('+').unwrap_or(("", shortcut)),
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            return None;
+        }
+
+        let mut hotkey = Hotkey {
+            primary: false,
+            modifiers: Modifiers::default(),
+            key: normalize_key(key),
+        };
+
+        for modifier in modifiers
+            .split('+')
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+        {
+            match modifier.to_ascii_lowercase().as_str() {
+                "mod" => hotkey.primary = true,
+                "ctrl" | "control" => hotkey.modifiers.ctrl = true,
+                "alt" | "option" | "opt" => hotkey.modifiers.alt = true,
+                "shift" => hotkey.modifiers.shift = true,
+                "meta" | "cmd" | "command" | "super" | "win" => hotkey.modifiers.meta = true,
+                _ => return None,
+            }
+        }
+
+        Some(hotkey)
+    }
+
+    /// Required modifiers with `Mod` resolved for the platform
+    pub fn modifiers_for(&self, apple: bool) -> Modifiers {
+        Modifiers {
+            ctrl: self.modifiers.ctrl || (self.primary && !apple),
+            meta: self.modifiers.meta || (self.primary && apple),
+            ..self.modifiers
+        }
+    }
+
+    /// Whether a key press matches this shortcut
+    ///
+    /// `key` and `code` are `KeyboardEvent.key` and `KeyboardEvent.code`. Letters and
+    /// digits also match by `code`, so `Alt+K` works where `Alt` changes the typed
+    /// character. Shift is ignored for symbols such as `+` or `?` unless the shortcut
+    /// requires it, since typing them needs Shift on some keyboard layouts only.
+    pub fn matches(&self, key: &str, code: &str, pressed: Modifiers, apple: bool) -> bool {
+        let mut required = self.modifiers_for(apple);
+        if self.is_symbol() && !required.shift {
+            required.shift = pressed.shift;
+        }
+        if pressed != required {
+            return false;
+        }
+
+        if key.eq_ignore_ascii_case(&self.key) {
+            return true;
+        }
+
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                code == format!("Key{}", c.to_ascii_uppercase())
+            }
+            (Some(c), None) if c.is_ascii_digit() => code == format!("Digit{c}"),
+            _ => false,
+        }
+    }
+
+    /// Whether the key is a printed symbol rather than a letter, digit or named key
+    fn is_symbol(&self) -> bool {
+        let mut chars = self.key.chars();
+        matches!(
+            (chars.next(), chars.next()),
+            (Some(c), None) if !c.is_alphanumeric() && !c.is_whitespace()
+        )
+    }
+
+    /// Labels of the keys to press, with symbols on Apple platforms
+    pub fn labels(&self, apple: bool) -> Vec<String> {
+        let modifiers = self.modifiers_for(apple);
+        let symbols = if apple {
+            [
+                (modifiers.ctrl, "⌃"),
+                (modifiers.alt, "⌥"),
+                (modifiers.shift, "⇧"),
+                (modifiers.meta, "⌘"),
+            ]
+        } else {
+            [
+                (modifiers.ctrl, "Ctrl"),
+                (modifiers.alt, "Alt"),
+                (modifiers.shift, "Shift"),
+                (modifiers.meta, "Meta"),
+            ]
+        };
+
+        symbols
+            .into_iter()
+            .filter_map(|(held, label)| held.then(|| label.to_string()))
+            .chain([key_label(&self.key, apple)])
+            .collect()
+    }
+}
+
+/// Options for [`use_hotkey_with_options`]
+#[derive(Clone, Copy, Debug)]
+pub struct HotkeyOptions {
+    /// Whether the hotkey is active
+    pub enabled: Signal<bool>,
+
+    /// Whether the hotkey also fires while typing in an input, textarea, select or
+    /// editable element
+    pub in_inputs: bool,
+
+    /// Whether to prevent the browser's default action for the shortcut
+    pub prevent_default: bool,
+}
+
+impl Default for HotkeyOptions {
+    fn default() -> Self {
+        HotkeyOptions {
+            enabled: Signal::from(true),
+            in_inputs: false,
+            prevent_default: true,
+        }
+    }
+}
+
+/// Calls `callback` when `shortcut` (e.g. `"Mod+K"`) is pressed anywhere on the page
+///
+/// Key presses while typing in an input, textarea, select or editable element are
+/// ignored, and the browser's default action is prevented. The listener is removed
+/// when the owning component is unmounted.
+pub fn use_hotkey(shortcut: &str, callback: impl Fn(ev::KeyboardEvent) + 'static) {
+    use_hotkey_with_options(shortcut, HotkeyOptions::default(), callback);
+}
+
+/// [`use_hotkey`] with explicit [`HotkeyOptions`]
+pub fn use_hotkey_with_options(
+    shortcut: &str,
+    options: HotkeyOptions,
+    callback: impl Fn(ev::KeyboardEvent) + 'static,
+) {
+    let Some(hotkey) = Hotkey::parse(shortcut) else {
+        logging::warn!("use_hotkey: invalid shortcut {shortcut:?}");
+        return;
+    };
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.is_composing() || !options.enabled.get_untracked() {
+            return;
+        }
+        if !options.in_inputs && is_editable(ev.target()) {
+            return;
+        }
+        if !hotkey.matches(
+            &ev.key(),
+            &ev.code(),
+            Modifiers::from_event(&ev),
+            is_apple_platform(),
+        ) {
+            return;
+        }
+
+        if options.prevent_default {
+            ev.prevent_default();
+        }
+        callback(ev);
+    });
+
+    on_cleanup(move || handle.remove());
+}
+
+/// Whether the browser runs on macOS or iOS, where `Mod` means `Command`
+///
+/// Only call this in the browser, e.g. from an effect or event handler.
+pub fn is_apple_platform() -> bool {
+    let navigator = window().navigator();
+    let platform = navigator.platform().unwrap_or_default();
+    let user_agent = navigator.user_agent().unwrap_or_default();
+
+    [platform, user_agent].iter().any(|value| {
+        ["Mac", "iPhone", "iPad", "iPod"]
+            .iter()
+            .any(|name| value.contains(name))
+    })
+}
+
+/// Whether the event target accepts typed text
+fn is_editable(target: Option<EventTarget>) -> bool {
+    let Some(element) = target.and_then(|target| target.dyn_into::<HtmlElement>().ok()) else {
+        return false;
+    };
+
+    if element.is_content_editable() {
+        return true;
+    }
+
+    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        return !matches!(
+            input.type_().as_str(),
+            "checkbox" | "radio" | "button" | "submit" | "reset" | "range" | "color" | "file"
+        );
+    }
+
+    element.is_instance_of::<HtmlTextAreaElement>() || element.is_instance_of::<HtmlSelectElement>()
+}
+
+/// Normalizes a key name to its `KeyboardEvent.key` value
+fn normalize_key(key: &str) -> String {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return c.to_lowercase().collect();
+    }
+
+    let named = match key.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => "Escape",
+        "enter" | "return" => "Enter",
+        "space" => " ",
+        "tab" => "Tab",
+        "backspace" => "Backspace",
+        "delete" | "del" => "Delete",
+        "up" | "arrowup" => "ArrowUp",
+        "down" | "arrowdown" => "ArrowDown",
+        "left" | "arrowleft" => "ArrowLeft",
+        "right" | "arrowright" => "ArrowRight",
+        "home" => "Home",
+        "end" => "End",
+        "pageup" => "PageUp",
+        "pagedown" => "PageDown",
+        lower if lower.starts_with('f') && lower[1..].parse::<u8>().is_ok() => {
+            return key.to_ascii_uppercase();
+        }
+        _ => key,
+    };
+
+    named.to_string()
+}
+
+/// Label of a normalized key
+fn key_label(key: &str, apple: bool) -> String {
+    let label = match (key, apple) {
+        (" ", _) => "Space",
+        ("Escape", _) => "Esc",
+        ("ArrowUp", _) => "↑",
+        ("ArrowDown", _) => "↓",
+        ("ArrowLeft", _) => "←",
+        ("ArrowRight", _) => "→",
+        ("Enter", true) => "↩",
+        ("Backspace", true) => "⌫",
+        ("Delete", true) => "⌦",
+        ("Delete", false) => "Del",
+        ("Tab", true) => "⇥",
+        _ => return key.to_uppercase(),
+    };
+
+    label.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn held(ctrl: bool, alt: bool, shift: bool, meta: bool) -> Modifiers {
+        Modifiers {
+            ctrl,
+            alt,
+            shift,
+            meta,
+        }
+    }
+
+    #[test]
+    fn parses_shortcuts() {
+        let hotkey = Hotkey::parse("Mod+Shift+K").unwrap();
+        assert!(hotkey.primary);
+        assert_eq!(hotkey.modifiers, held(false, false, true, false));
+        assert_eq!(hotkey.key, "k");
+
+        assert_eq!(Hotkey::parse("ctrl + alt + del").unwrap().key, "Delete");
+        assert_eq!(Hotkey::parse("Mod++").unwrap().key, "+");
+        assert_eq!(Hotkey::parse("Escape").unwrap().key, "Escape");
+        assert_eq!(Hotkey::parse("Shift+f5").unwrap().key, "F5");
+        assert_eq!(Hotkey::parse("Hyper+K"), None);
+        assert_eq!(Hotkey::parse("Mod+"), None);
+        assert_eq!(Hotkey::parse(""), None);
+    }
+
+    #[test]
+    fn resolves_mod_per_platform() {
+        let hotkey = Hotkey::parse("Mod+K").unwrap();
+        assert_eq!(hotkey.modifiers_for(true), held(false, false, false, true));
+        assert_eq!(hotkey.modifiers_for(false), held(true, false, false, false));
+    }
+
+    #[test]
+    fn matches_key_presses() {
+        let hotkey = Hotkey::parse("Mod+Shift+K").unwrap();
+        assert!(hotkey.matches("K", "KeyK", held(false, false, true, true), true));
+        assert!(hotkey.matches("K", "KeyK", held(true, false, true, false), false));
+        assert!(!hotkey.matches("K", "KeyK", held(true, false, true, false), true));
+        assert!(!hotkey.matches("K", "KeyK", held(false, false, false, true), true));
+
+        let alt = Hotkey::parse("Alt+K").unwrap();
+        assert!(alt.matches("˚", "KeyK", held(false, true, false, false), true));
+        assert!(!alt.matches("j", "KeyJ", held(false, true, false, false), true));
+    }
+
+    #[test]
+    fn ignores_shift_for_symbols() {
+        let plus = Hotkey::parse("Mod++").unwrap();
+        assert!(plus.matches("+", "Equal", held(true, false, true, false), false));
+        assert!(plus.matches("+", "NumpadAdd", held(true, false, false, false), false));
+        assert!(!plus.matches("+", "Equal", held(true, true, true, false), false));
+
+        let help = Hotkey::parse("Shift+?").unwrap();
+        assert!(help.matches("?", "Slash", held(false, false, true, false), false));
+        assert!(!help.matches("?", "Slash", held(false, false, false, false), false));
+
+        let letter = Hotkey::parse("Mod+K").unwrap();
+        assert!(!letter.matches("K", "KeyK", held(true, false, true, false), false));
+    }
+
+    #[test]
+    fn labels_keys_per_platform() {
+        let hotkey = Hotkey::parse("Mod+Shift+K").unwrap();
+        assert_eq!(hotkey.labels(true), vec!["⇧", "⌘", "K"]);
+        assert_eq!(hotkey.labels(false), vec!["Ctrl", "Shift", "K"]);
+        assert_eq!(
+            Hotkey::parse("Mod+Enter").unwrap().labels(true),
+            vec!["⌘", "↩"]
+        );
+        assert_eq!(Hotkey::parse("Space").unwrap().labels(false), vec!["Space"]);
+    }
+}
@@ -0,0 +1,39 @@
+use leptos::prelude::*;
+#[cfg(feature = "icons")]
+use leptos_icons::Icon;
+
+/// An icon for the `icon_start`/`icon_end` slots of components
+///
+/// With the `icons` feature this is an [`icondata`] icon, e.g. `icondata::AiHomeOutlined`.
+#[cfg(feature = "icons")]
+pub type IconSlot = icondata::Icon;
+
+/// An icon for the `icon_start`/`icon_end` slots of components
+///
+/// Icons require the `icons` feature; without it this type has no values and the slots
+/// render nothing.
+#[cfg(not(feature = "icons"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IconSlot {}
+
+/// Renders an optional icon with the given CSS width and height
+#[cfg(feature = "icons")]
+pub fn icon_slot(
+    icon: impl Fn() -> Option<IconSlot> + Send + Sync + 'static,
+    size: impl Fn() -> &'static str + Send + Sync + 'static,
+) -> impl IntoView {
+    move || {
+        icon().map(|icon| {
+            let size = size();
+            view! { <Icon icon=icon width=size height=size /> }
+        })
+    }
+}
+
+/// Renders an optional icon with the given CSS width and height
+#[cfg(not(feature = "icons"))]
+pub fn icon_slot(
+    _icon: impl Fn() -> Option<IconSlot> + Send + Sync + 'static,
+    _size: impl Fn() -> &'static str + Send + Sync + 'static,
+) -> impl IntoView {
+}
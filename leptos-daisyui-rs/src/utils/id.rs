@@ -0,0 +1,53 @@
+use hydration_context::SharedContext;
+use leptos::prelude::*;
+use std::cell::Cell;
+
+thread_local! {
+    static NEXT_CLIENT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Generates an element id with the given prefix that is unique within the current render
+///
+/// Used by components that need to wire `id`/`for`/`name` attributes between
+/// their elements without asking the caller for a hand-written identifier.
+///
+/// When server rendering or hydrating, the number comes from the shared context of the
+/// current render, so the server and the browser assign the same ids in the same order
+/// and every request starts over. In client-side rendered apps, where there is nothing
+/// to hydrate, a counter local to the browser thread is used instead.
+pub fn unique_id(prefix: &str) -> String {
+    let id = Owner::current_shared_context()
+        .map(|sc| sc.next_id().into_inner())
+        .unwrap_or_else(|| NEXT_CLIENT_ID.with(|next| next.replace(next.get() + 1)));
+
+    format!("{prefix}-{id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hydration_context::SsrSharedContext;
+    use std::sync::Arc;
+
+    #[test]
+    fn unique_id_uses_prefix() {
+        assert!(unique_id("drawer").starts_with("drawer-"));
+    }
+
+    #[test]
+    fn unique_id_is_unique() {
+        assert_ne!(unique_id("drawer"), unique_id("drawer"));
+    }
+
+    #[test]
+    fn unique_id_is_scoped_to_the_render() {
+        let render = || {
+            Owner::new_root(Some(Arc::new(SsrSharedContext::new())))
+                .with(|| (unique_id("drawer"), unique_id("drawer")))
+        };
+
+        let (first, second) = render();
+        assert_ne!(first, second);
+        assert_eq!(render(), (first, second));
+    }
+}
@@ -1,5 +1,17 @@
 //! Utility functions and types for daisyUI components
 
 mod class_attribute;
+mod focus_trap;
+mod hotkey;
+mod icon;
+mod id;
+mod route;
+mod scroll_lock;
 
 pub use class_attribute::*;
+pub use focus_trap::*;
+pub use hotkey::*;
+pub use icon::*;
+pub use id::*;
+pub use route::*;
+pub use scroll_lock::*;
@@ -0,0 +1,82 @@
+use leptos::prelude::*;
+
+/// How a link's `href` is compared with the current location to decide whether it is active
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouteMatch {
+    /// Active only when the path equals the `href`
+    #[default]
+    Exact,
+
+    /// Active when the path equals the `href` or is nested below it
+    Prefix,
+}
+
+/// Whether the current router location matches `href`
+///
+/// Only available with the `router` feature, and must be called inside a `<Router>`.
+#[cfg(feature = "router")]
+pub fn use_route_active(
+    href: impl Into<Signal<String>>,
+    route_match: impl Into<Signal<RouteMatch>>,
+) -> Signal<bool> {
+    let href = href.into();
+    let route_match = route_match.into();
+    let location = leptos_router::hooks::use_location();
+
+    Signal::derive(move || {
+        let href = href.get();
+        location
+            .pathname
+            .with(|path| route_matches(&href, path, route_match.get()))
+    })
+}
+
+/// Compares a link `href` with a location path
+///
+/// Query strings, fragments and trailing slashes are ignored. External and empty links
+/// never match.
+pub fn route_matches(href: &str, path: &str, route_match: RouteMatch) -> bool {
+    let href = href.split(['?', '#']).next().unwrap_or_default();
+    if !href.starts_with('/') {
+        return false;
+    }
+
+    let href = href.trim_end_matches('/');
+    let path = path.trim_end_matches('/');
+
+    match route_match {
+        RouteMatch::Exact => href == path,
+        RouteMatch::Prefix => {
+            href.is_empty()
+                || path
+                    .strip_prefix(href)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_ignores_trailing_slash_and_query() {
+        assert!(route_matches("/docs/", "/docs", RouteMatch::Exact));
+        assert!(route_matches("/docs?tab=1", "/docs", RouteMatch::Exact));
+        assert!(!route_matches("/docs", "/docs/intro", RouteMatch::Exact));
+    }
+
+    #[test]
+    fn prefix_match_requires_segment_boundary() {
+        assert!(route_matches("/docs", "/docs/intro", RouteMatch::Prefix));
+        assert!(route_matches("/docs", "/docs", RouteMatch::Prefix));
+        assert!(!route_matches("/docs", "/docsify", RouteMatch::Prefix));
+        assert!(route_matches("/", "/anything", RouteMatch::Prefix));
+    }
+
+    #[test]
+    fn external_and_empty_links_never_match() {
+        assert!(!route_matches("", "/", RouteMatch::Exact));
+        assert!(!route_matches("https://example.com/", "/", RouteMatch::Exact));
+    }
+}
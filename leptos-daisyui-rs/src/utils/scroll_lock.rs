@@ -0,0 +1,111 @@
+use leptos::{prelude::*, web_sys};
+use std::{
+    cell::RefCell,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+thread_local! {
+    /// Number of active locks and the body styles to restore once all are released
+    static SCROLL_LOCK: RefCell<ScrollLockState> = RefCell::new(ScrollLockState::default());
+}
+
+#[derive(Default)]
+struct ScrollLockState {
+    count: usize,
+    overflow: String,
+    padding_right: String,
+}
+
+/// Prevents the page behind an overlay from scrolling while `locked` is true
+///
+/// Sets `overflow: hidden` on `<body>` and pads it by the scrollbar width so the
+/// layout does not shift. Locks are reference counted, so nested overlays (for example
+/// a modal opened from a drawer) only restore scrolling once the last one releases.
+/// The lock is also released when the owning component is unmounted.
+pub fn use_scroll_lock(locked: impl Into<Signal<bool>>) {
+    let locked = locked.into();
+    let holding = Arc::new(AtomicBool::new(false));
+
+    Effect::new({
+        let holding = Arc::clone(&holding);
+        move |_| {
+            let should_lock = locked.get();
+
+            if should_lock != holding.swap(should_lock, Ordering::Relaxed) {
+                if should_lock {
+                    lock_body();
+                } else {
+                    unlock_body();
+                }
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        if holding.swap(false, Ordering::Relaxed) {
+            unlock_body();
+        }
+    });
+}
+
+fn lock_body() {
+    let Some(body) = document().body() else {
+        return;
+    };
+
+    SCROLL_LOCK.with_borrow_mut(|state| {
+        state.count += 1;
+        if state.count > 1 {
+            return;
+        }
+
+        let style = body.style();
+        state.overflow = style.get_property_value("overflow").unwrap_or_default();
+        state.padding_right = style
+            .get_property_value("padding-right")
+            .unwrap_or_default();
+
+        let viewport_width = window()
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default();
+        let scrollbar_width = document()
+            .document_element()
+            .map(|root| viewport_width - f64::from(root.client_width()))
+            .unwrap_or_default();
+
+        let _ = style.set_property("overflow", "hidden");
+        if scrollbar_width > 0.0 {
+            let _ = style.set_property("padding-right", &format!("{scrollbar_width}px"));
+        }
+    });
+}
+
+fn unlock_body() {
+    let Some(body) = document().body() else {
+        return;
+    };
+
+    SCROLL_LOCK.with_borrow_mut(|state| {
+        state.count = state.count.saturating_sub(1);
+        if state.count > 0 {
+            return;
+        }
+
+        let style = body.style();
+        restore_property(&style, "overflow", &state.overflow);
+        restore_property(&style, "padding-right", &state.padding_right);
+    });
+}
+
+fn restore_property(style: &web_sys::CssStyleDeclaration, name: &str, value: &str) {
+    if value.is_empty() {
+        let _ = style.remove_property(name);
+    } else {
+        let _ = style.set_property(name, value);
+    }
+}